@@ -0,0 +1,1118 @@
+use crate::automaton::Automaton;
+use crate::error::ParseError;
+use crate::explain::{Color, Explainer, Style};
+use crate::generate::{Generator, Options, Rng};
+use crate::parse::Cursor;
+use crate::solution::Solution;
+use crate::stream::{self, StreamError};
+use std::convert::Infallible;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    const GENERATE: Option<Generator> = Some(generate);
+    const EXPLAIN: Option<Explainer> = Some(write_explanation);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Display {
+        calibrate(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl Display {
+        calibrate_with_words(input, puzzle_vocabulary())
+    }
+}
+
+// part 1
+/// Sum of the first and last digit of every line
+///
+/// Scans the bytes without a fallback to `chars`: only ASCII digits count like in
+/// `calibration_value`, and UTF-8 never uses ASCII bytes inside a multi-byte character, so both
+/// agree on every `&str`. Use `try_calibrate` in strict mode to find numerals like `½` or `٣`.
+pub fn calibrate(input: &str) -> u64 {
+    calibrate_bytes(input.as_bytes())
+}
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGH: u64 = 0x8080_8080_8080_8080;
+
+/// High bit of every byte of `word` that is `byte`
+fn equal_bytes(word: u64, byte: u8) -> u64 {
+    let x = word ^ (ONES * u64::from(byte));
+
+    // adding 0x7f to the low 7 bits sets the high bit unless they are all 0, and never carries into
+    // the next byte
+    !(((x & !HIGH) + !HIGH) | x) & HIGH
+}
+
+/// High bit of every byte of `word` that is an ASCII digit
+fn digit_bytes(word: u64) -> u64 {
+    // digits become 0 to 9 and nothing else does
+    let x = word ^ (ONES * u64::from(b'0'));
+
+    // adding 0x76 to the low 7 bits sets the high bit from 10 on
+    !(((x & !HIGH) + ONES * 0x76) | x) & HIGH
+}
+
+/// One bit per byte of `block`, set where `mask` sets the high bit of the byte in its word
+fn block_bits(block: &[u8; 64], mask: impl Fn(u64) -> u64) -> u64 {
+    block
+        .chunks_exact(8)
+        .enumerate()
+        .fold(0, |bits, (index, chunk)| {
+            let word = u64::from_le_bytes(chunk.try_into().expect("chunks have 8 bytes"));
+            // moves the high bit of byte i to bit 56 + i, every partial product lands on its own bit
+            let gathered = (mask(word) >> 7).wrapping_mul(0x0102_0408_1020_4080) >> 56;
+
+            bits | gathered << (index * 8)
+        })
+}
+
+/// Part 1 over raw bytes, finding digits and line breaks 64 bytes at a time
+///
+/// Bytes that are not ASCII are skipped like letters, see `calibrate`. The masks of a block cost
+/// little next to the few branches per line, so short lines are what limits the throughput, see the
+/// benchmark section of the README.
+pub fn calibrate_bytes(input: &[u8]) -> u64 {
+    let mut result = 0;
+    let mut first: Option<u64> = None;
+    let mut last = 0;
+
+    let mut scan = |block: &[u8; 64]| {
+        let mut digits = block_bits(block, digit_bytes);
+        let mut newlines = block_bits(block, |word| equal_bytes(word, b'\n'));
+        let digit = |bit: u32| u64::from(block[bit as usize] - b'0');
+
+        while newlines != 0 {
+            // bit i is byte i of the block
+            let newline = newlines & newlines.wrapping_neg();
+            let line = digits & (newline - 1);
+
+            if line != 0 {
+                first.get_or_insert(digit(line.trailing_zeros()));
+                last = digit(63 - line.leading_zeros());
+            }
+
+            if let Some(first) = first.take() {
+                result += first * 10 + last;
+            }
+
+            newlines ^= newline;
+            digits &= !(newline | (newline - 1));
+        }
+
+        // digits after the last line break belong to a line that continues in the next block
+        if digits != 0 {
+            first.get_or_insert(digit(digits.trailing_zeros()));
+            last = digit(63 - digits.leading_zeros());
+        }
+    };
+
+    let chunks = input.chunks_exact(64);
+    let rest = chunks.remainder();
+
+    for chunk in chunks {
+        scan(chunk.try_into().expect("chunks have 64 bytes"));
+    }
+
+    // the rest is padded with zeros, which are neither digits nor line breaks
+    let mut bytes = [0; 64];
+    bytes[..rest.len()].copy_from_slice(rest);
+    scan(&bytes);
+
+    if let Some(first) = first {
+        result += first * 10 + last;
+    }
+
+    result
+}
+
+/// Streaming variant of part 1 that only holds one line of the input in memory
+pub fn calibrate_reader(reader: impl BufRead) -> Result<u64, StreamError> {
+    let mut result = 0;
+
+    stream::for_each_line(reader, |_, line| {
+        result += calibration_value(line);
+        Ok(())
+    })?;
+
+    Ok(result)
+}
+
+fn calibration_value(line: &str) -> u64 {
+    let mut result = 0;
+
+    // only ASCII digits count, other numeric chars like ½ have no decimal value
+    //
+    // iterate from front until the first numeric char appears add that to res multiplied
+    // by 10 because assuming as2df1g this should be 21 so the first number is basically
+    // 20 and the last one 1 making this 21
+    for char in line.chars() {
+        if let Some(val) = char.to_digit(10) {
+            result += u64::from(val) * 10;
+            break;
+        }
+    }
+
+    // for the second number we do exactly the same just backwards
+    for char in line.chars().rev() {
+        if let Some(val) = char.to_digit(10) {
+            result += u64::from(val);
+            break;
+        }
+    }
+
+    result
+}
+
+// part 2
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const ROMAN: [&str; 9] = ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+
+const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// Built in words for the digits one to nine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    German,
+    French,
+    /// Upper case numerals `I` to `IX`
+    Roman,
+}
+
+impl Language {
+    const NAMES: [(&'static str, Language); 4] = [
+        ("english", Language::English),
+        ("german", Language::German),
+        ("french", Language::French),
+        ("roman", Language::Roman),
+    ];
+
+    pub fn words(self) -> [&'static str; 9] {
+        match self {
+            Language::English => ENGLISH,
+            Language::German => GERMAN,
+            Language::French => FRENCH,
+            Language::Roman => ROMAN,
+        }
+    }
+}
+
+/// Tokens that count as digits when calibrating, the ASCII digits always do and words can be
+/// added on top, the default is the puzzle's `one` to `nine`
+///
+/// ```
+/// use advent_of_code::y2023::day01::{calibrate_with_words, DigitVocabulary, Language};
+///
+/// let vocabulary = DigitVocabulary::digits()
+///     .with_language(Language::German)
+///     .with_word("null", 0)
+///     .ignore_case(true);
+///
+/// assert_eq!(calibrate_with_words("Zweiundvierzig\nnull8", &vocabulary), 24 + 8);
+/// ```
+///
+/// It can also be read from a config file with one entry per line, blank lines and lines starting
+/// with `#` are skipped
+///
+/// ```text
+/// # every word of a built in language, english, german, french or roman
+/// language german
+/// # ASCII letters match regardless of their case, the default is case sensitive
+/// case insensitive
+/// # a single word and the digit it stands for
+/// null = 0
+/// ```
+pub struct DigitVocabulary {
+    words: Vec<(String, u32)>,
+    ignore_case: bool,
+    tokens: OnceLock<Automaton<(u32, TokenKind)>>,
+}
+
+impl DigitVocabulary {
+    /// Only the ASCII digits `0` to `9`
+    pub fn digits() -> Self {
+        Self {
+            words: vec![],
+            ignore_case: false,
+            tokens: OnceLock::new(),
+        }
+    }
+
+    /// Adds the words for one to nine in `language`
+    pub fn with_language(self, language: Language) -> Self {
+        language
+            .words()
+            .into_iter()
+            .zip(1..)
+            .fold(self, |vocabulary, (word, value)| {
+                vocabulary.with_word(word, value)
+            })
+    }
+
+    /// Adds a word for `value`, a word added twice stands for the last value
+    ///
+    /// # Panics
+    ///
+    /// If the word is empty or the value is not a single digit.
+    pub fn with_word(mut self, word: impl Into<String>, value: u32) -> Self {
+        let word = word.into();
+        assert!(!word.is_empty(), "words should not be empty");
+        assert!(
+            value <= 9,
+            "{word} should stand for a single digit, not {value}"
+        );
+
+        self.words.push((word, value));
+        self.tokens = OnceLock::new();
+        self
+    }
+
+    /// Whether ASCII letters of words match regardless of their case
+    pub fn ignore_case(mut self, ignore: bool) -> Self {
+        self.ignore_case = ignore;
+        self.tokens = OnceLock::new();
+        self
+    }
+
+    /// Matches the words and the ASCII digits in one pass, built on first use
+    fn tokens(&self) -> &Automaton<(u32, TokenKind)> {
+        self.tokens.get_or_init(|| {
+            let digits = DIGITS
+                .into_iter()
+                .map(|(digit, value)| (digit, (value, TokenKind::Digit)));
+            let words = self
+                .words
+                .iter()
+                .map(|(word, value)| (word.as_str(), (*value, TokenKind::Word)));
+            let patterns = digits.chain(words);
+
+            if self.ignore_case {
+                Automaton::ignoring_ascii_case(patterns)
+            } else {
+                Automaton::new(patterns)
+            }
+        })
+    }
+
+    /// First and last digit of `line` combined into a two digit number, 0 if there are none
+    pub fn calibration_value(&self, line: &str) -> u64 {
+        match self.first_and_last(line) {
+            (Some(first), Some(last)) => u64::from(first.value * 10 + last.value),
+            _ => 0,
+        }
+    }
+
+    /// The tokens the first and the last digit of `line` come from
+    ///
+    /// Tokens may overlap, in `eightwo` the first digit is 8 and the last one 2. Of tokens starting
+    /// at the same byte the longest is the first one, and of tokens ending at the same byte the
+    /// longest is the last one, so `VIII` is 88 with Roman numerals.
+    pub fn first_and_last(&self, line: &str) -> (Option<Token>, Option<Token>) {
+        let mut first: Option<Token> = None;
+        let mut last: Option<Token> = None;
+
+        let longer = |a: &Token, b: &Token| a.end - a.start > b.end - b.start;
+
+        for found in self.tokens().find_iter(line.as_bytes()) {
+            let (value, kind) = found.value;
+            let token = Token {
+                start: found.start,
+                end: found.end,
+                value,
+                kind,
+            };
+
+            if first.is_none_or(|first| {
+                token.start < first.start || token.start == first.start && longer(&token, &first)
+            }) {
+                first = Some(token);
+            }
+
+            if last.is_none_or(|last| {
+                token.end > last.end || token.end == last.end && longer(&token, &last)
+            }) {
+                last = Some(token);
+            }
+        }
+
+        (first, last)
+    }
+
+    /// Whether the byte at `index` of `line` is part of a word
+    fn in_word(&self, line: &str, index: usize) -> bool {
+        self.tokens().find_iter(line.as_bytes()).any(|found| {
+            found.value.1 == TokenKind::Word && (found.start..found.end).contains(&index)
+        })
+    }
+}
+
+impl Default for DigitVocabulary {
+    /// The ASCII digits and `one` to `nine`, as in the puzzle
+    fn default() -> Self {
+        Self::digits().with_language(Language::English)
+    }
+}
+
+impl FromStr for DigitVocabulary {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut vocabulary = Self::digits();
+
+        for (index, line) in s.lines().enumerate() {
+            let mut cursor = Cursor::new(index + 1, line);
+
+            if cursor.is_at_end() || cursor.eat("#") {
+                continue;
+            }
+
+            let key = cursor.word()?;
+            let setting = !cursor.rest().trim_start().starts_with('=');
+
+            vocabulary = match key {
+                "language" if setting => vocabulary.with_language(cursor.one_of(&Language::NAMES)?),
+                "case" if setting => vocabulary
+                    .ignore_case(cursor.one_of(&[("insensitive", true), ("sensitive", false)])?),
+                _ => {
+                    cursor.literal("=")?;
+                    let value =
+                        cursor.labelled("a digit from 0 to 9", |cursor| cursor.one_of(&DIGITS))?;
+
+                    vocabulary.with_word(key, value)
+                }
+            };
+
+            cursor.end()?;
+        }
+
+        Ok(vocabulary)
+    }
+}
+
+/// The vocabulary of the puzzle, shared so part 2 only builds it once
+fn puzzle_vocabulary() -> &'static DigitVocabulary {
+    static VOCABULARY: OnceLock<DigitVocabulary> = OnceLock::new();
+
+    VOCABULARY.get_or_init(DigitVocabulary::default)
+}
+
+pub fn calibrate_with_words(input: &str, vocabulary: &DigitVocabulary) -> u64 {
+    input
+        .lines()
+        .map(|line| vocabulary.calibration_value(line))
+        .sum()
+}
+
+/// Streaming variant of part 2 that only holds one line of the input in memory
+pub fn calibrate_with_words_reader(
+    reader: impl BufRead,
+    vocabulary: &DigitVocabulary,
+) -> Result<u64, StreamError> {
+    let mut result = 0;
+
+    stream::for_each_line(reader, |_, line| {
+        result += vocabulary.calibration_value(line);
+        Ok(())
+    })?;
+
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// An ASCII digit
+    Digit,
+    /// A word of the vocabulary
+    Word,
+}
+
+/// A digit found at the bytes `start..end` of a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+    pub kind: TokenKind,
+}
+
+/// Which tokens of a line its calibration value comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    /// Line number, starting at 1
+    pub number: usize,
+    pub line: &'a str,
+    pub first: Option<Token>,
+    pub last: Option<Token>,
+}
+
+impl Explanation<'_> {
+    pub fn value(&self) -> u64 {
+        match (self.first, self.last) {
+            (Some(first), Some(last)) => u64::from(first.value * 10 + last.value),
+            _ => 0,
+        }
+    }
+
+    /// The part of the line `token` was found in, tokens always cover whole characters
+    pub fn text(&self, token: &Token) -> &str {
+        &self.line[token.start..token.end]
+    }
+}
+
+/// Explains the calibration value of every line of `input`
+pub fn explain<'a>(input: &'a str, vocabulary: &DigitVocabulary) -> Vec<Explanation<'a>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (first, last) = vocabulary.first_and_last(line);
+
+            Explanation {
+                number: index + 1,
+                line,
+                first,
+                last,
+            }
+        })
+        .collect()
+}
+
+/// How calibration treats lines it cannot take a value from for sure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CalibrationMode {
+    /// Lines without digits count as 0 and numeric characters other than ASCII digits are
+    /// ignored, as in the puzzle
+    #[default]
+    Lenient,
+    /// Lines without digits and numeric characters other than ASCII digits outside of words, like
+    /// `½` or `٣`, are errors
+    Strict,
+}
+
+/// Every line strict calibration rejected, in input order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalibrationError {
+    pub lines: Vec<ParseError>,
+}
+
+impl Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, err) in self.lines.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+                writeln!(f)?;
+            }
+
+            write!(f, "{err}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Part 1 that can reject lines instead of guessing their value, see `CalibrationMode`
+pub fn try_calibrate(input: &str, mode: CalibrationMode) -> Result<u64, CalibrationError> {
+    match mode {
+        CalibrationMode::Lenient => Ok(calibrate(input)),
+        CalibrationMode::Strict => calibrate_strictly(input, &DigitVocabulary::digits()),
+    }
+}
+
+/// Part 2 that can reject lines instead of guessing their value, see `CalibrationMode`
+pub fn try_calibrate_with_words(
+    input: &str,
+    vocabulary: &DigitVocabulary,
+    mode: CalibrationMode,
+) -> Result<u64, CalibrationError> {
+    match mode {
+        CalibrationMode::Lenient => Ok(calibrate_with_words(input, vocabulary)),
+        CalibrationMode::Strict => calibrate_strictly(input, vocabulary),
+    }
+}
+
+fn calibrate_strictly(input: &str, vocabulary: &DigitVocabulary) -> Result<u64, CalibrationError> {
+    let mut result = 0;
+    let mut lines = vec![];
+
+    for explanation in explain(input, vocabulary) {
+        let (number, line) = (explanation.number, explanation.line);
+
+        let numeric = line.char_indices().find(|(index, char)| {
+            char.is_numeric() && !char.is_ascii_digit() && !vocabulary.in_word(line, *index)
+        });
+
+        if let Some((index, char)) = numeric {
+            let span = index..index + char.len_utf8();
+            lines.push(ParseError::new(number, line, span, "an ASCII digit"));
+        } else if explanation.first.is_none() {
+            lines.push(ParseError::new(
+                number,
+                line,
+                0..line.len(),
+                "at least one digit",
+            ));
+        } else {
+            result += explanation.value();
+        }
+    }
+
+    if lines.is_empty() {
+        Ok(result)
+    } else {
+        Err(CalibrationError { lines })
+    }
+}
+
+/// Writes every line with its first digit in green and its last one in blue, cyan where they
+/// overlap, followed by the tokens and the calibration value
+fn write_explanation(input: &str, part: u8, style: Style, out: &mut dyn Write) -> io::Result<()> {
+    let digits = DigitVocabulary::digits();
+    let vocabulary = if part == 1 {
+        &digits
+    } else {
+        puzzle_vocabulary()
+    };
+
+    for explanation in explain(input, vocabulary) {
+        write!(out, "{:>4}: ", explanation.number)?;
+        write_highlighted(&explanation, style, out)?;
+
+        let describe = |token: &Token| {
+            let kind = match token.kind {
+                TokenKind::Digit => "digit",
+                TokenKind::Word => "word",
+            };
+
+            format!(
+                "{kind} `{}` at {}..{}",
+                explanation.text(token),
+                token.start,
+                token.end
+            )
+        };
+
+        match (&explanation.first, &explanation.last) {
+            (Some(first), Some(last)) => writeln!(
+                out,
+                "  = {} (first {}, last {})",
+                explanation.value(),
+                describe(first),
+                describe(last)
+            )?,
+            _ => writeln!(out, "  = 0 (no digits)")?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_highlighted(
+    explanation: &Explanation,
+    style: Style,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let line = explanation.line;
+    let tokens = [explanation.first, explanation.last];
+
+    let mut bounds: Vec<usize> = tokens
+        .iter()
+        .flatten()
+        .flat_map(|token| [token.start, token.end])
+        .chain([0, line.len()])
+        .collect();
+    bounds.sort();
+    bounds.dedup();
+
+    for window in bounds.windows(2) {
+        let text = &line[window[0]..window[1]];
+        let covers = |token: Option<Token>| {
+            token.is_some_and(|token| (token.start..token.end).contains(&window[0]))
+        };
+
+        match (covers(explanation.first), covers(explanation.last)) {
+            (true, true) => write!(out, "{}", style.paint(text, Color::Cyan))?,
+            (true, false) => write!(out, "{}", style.paint(text, Color::Green))?,
+            (false, true) => write!(out, "{}", style.paint(text, Color::Blue))?,
+            (false, false) => write!(out, "{text}")?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Spelled out digits sharing letters, `eightwo` has to count as both `eight` and `two`
+const OVERLAPS: [&str; 8] = [
+    "oneight",
+    "twone",
+    "threeight",
+    "fiveight",
+    "sevenine",
+    "eightwo",
+    "eighthree",
+    "nineight",
+];
+
+/// `size` lines of letters, digits and spelled out digits, `density` is the share of spelled out
+/// digits, a third of which overlap with the next one
+fn generate(rng: &mut Rng, options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let lines = options.size.unwrap_or(1000);
+    let density = options.density.unwrap_or(0.3);
+    let mut line = String::new();
+
+    for _ in 0..lines {
+        line.clear();
+
+        for _ in 0..rng.between(1, 10) {
+            if !rng.chance(density) {
+                let (first, count) = if rng.chance(0.3) {
+                    (b'1', 9)
+                } else {
+                    (b'a', 26)
+                };
+                line.push((first + rng.below(count) as u8) as char);
+            } else {
+                let words: &[&str] = if rng.chance(1.0 / 3.0) {
+                    &OVERLAPS
+                } else {
+                    &ENGLISH
+                };
+                let word = rng.pick(words);
+                line.push_str(word);
+            }
+        }
+
+        // every line of a real input has at least one digit
+        let digit = (b'1' + rng.below(9) as u8) as char;
+        line.insert(rng.between(0, line.len()), digit);
+
+        writeln!(out, "{line}")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_2023_01 {
+    use super::{
+        calibrate, calibrate_bytes, calibrate_reader, calibrate_with_words,
+        calibrate_with_words_reader, digit_bytes, equal_bytes, explain, try_calibrate,
+        try_calibrate_with_words, write_explanation, CalibrationMode, DigitVocabulary, Language,
+        Token, TokenKind,
+    };
+    use crate::explain::Style;
+    use proptest::prelude::*;
+
+    const TEST_INPUT_CALIBRATE: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    #[test]
+    fn test_calibrate() {
+        assert_eq!(calibrate(TEST_INPUT_CALIBRATE), 142);
+    }
+
+    const TEST_INPUT_CALIBRATE_WITH_WORDS: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn test_calibrate_with_words() {
+        let vocabulary = DigitVocabulary::default();
+        assert_eq!(
+            calibrate_with_words(TEST_INPUT_CALIBRATE_WITH_WORDS, &vocabulary),
+            281
+        );
+    }
+
+    #[test]
+    fn test_byte_masks() {
+        for byte in 0..=u8::MAX {
+            for position in 0..8 {
+                // surrounded by bytes that are neither, both before and after
+                let mut bytes = [0xff; 8];
+                bytes[position] = byte;
+                let word = u64::from_le_bytes(bytes);
+                let bit = 0x80 << (position * 8);
+
+                let digit = if byte.is_ascii_digit() { bit } else { 0 };
+                assert_eq!(digit, digit_bytes(word), "{byte:#x} at {position}");
+
+                let newline = if byte == b'\n' { bit } else { 0 };
+                assert_eq!(newline, equal_bytes(word, b'\n'), "{byte:#x} at {position}");
+            }
+        }
+
+        assert_eq!(
+            0x80_80_00_80,
+            digit_bytes(u64::from_le_bytes(*b"1a23\n\n\n\n"))
+        );
+    }
+
+    #[test]
+    fn test_calibrate_bytes() {
+        assert_eq!(
+            12 + 38 + 15 + 77,
+            calibrate_bytes(TEST_INPUT_CALIBRATE.as_bytes())
+        );
+        assert_eq!(0, calibrate_bytes(b""));
+        assert_eq!(
+            11 + 99,
+            calibrate_bytes(b"\n\n1\r\n\nabcdefghijklmnopq9rstuvwxyz\n")
+        );
+        assert_eq!(18, calibrate_bytes(b"12345678\xff\xfe8"));
+
+        // lines and their digits on both sides of the 64 byte blocks
+        let input = format!(
+            "{}\n{}7{}3\n{}\n5",
+            [TEST_INPUT_CALIBRATE; 4].join("\n"),
+            "x".repeat(60),
+            "y".repeat(70),
+            "z".repeat(64)
+        );
+        assert_eq!(
+            calibrate_reader(input.as_bytes()).unwrap(),
+            calibrate_bytes(input.as_bytes())
+        );
+        assert_eq!(4 * 142 + 73 + 55, calibrate_bytes(input.as_bytes()));
+    }
+
+    #[test]
+    fn test_non_ascii_numerics() {
+        assert_eq!(calibrate("a½b٣c7"), 77);
+        assert_eq!(
+            calibrate_with_words("½one٣", &DigitVocabulary::default()),
+            11
+        );
+    }
+
+    #[test]
+    fn test_languages() {
+        let german = DigitVocabulary::digits().with_language(Language::German);
+        assert_eq!(85, german.calibration_value("achtundfünfzig"));
+        assert_eq!(0, german.calibration_value("Acht"));
+
+        let french = DigitVocabulary::digits().with_language(Language::French);
+        assert_eq!(39, french.calibration_value("troisneuf"));
+
+        // the longest numeral starting first and the longest ending last
+        let roman = DigitVocabulary::digits().with_language(Language::Roman);
+        assert_eq!(84, roman.calibration_value("chapter VIII, verse IV"));
+        assert_eq!(88, roman.calibration_value("VIII"));
+        assert_eq!(0, roman.calibration_value("viii"));
+
+        let english = DigitVocabulary::digits().with_language(Language::English);
+        assert_eq!(82, english.calibration_value("eightwo"));
+        assert_eq!(0, DigitVocabulary::digits().calibration_value("eightwo"));
+    }
+
+    #[test]
+    fn test_custom_words() {
+        let vocabulary = DigitVocabulary::default()
+            .with_word("zero", 0)
+            .with_word("Nil", 0)
+            .ignore_case(true);
+
+        assert_eq!(0, vocabulary.calibration_value("zero and nil"));
+        assert_eq!(30, vocabulary.calibration_value("THREE, two, NIL"));
+        assert_eq!(77, vocabulary.calibration_value("SEVEN"));
+
+        let vocabulary = vocabulary.ignore_case(false).with_word("nine", 6);
+        assert_eq!(16, vocabulary.calibration_value("one NIL nine"));
+    }
+
+    #[test]
+    fn test_strict_mode() {
+        let input = "a½b7\nabc\n\nx1y\nsix";
+
+        assert_eq!(Ok(77 + 11), try_calibrate(input, CalibrationMode::Lenient));
+
+        let err = try_calibrate(input, CalibrationMode::Strict).unwrap_err();
+        let lines: Vec<_> = err
+            .lines
+            .iter()
+            .map(|err| (err.line, err.column(), err.found.as_str()))
+            .collect();
+        assert_eq!(
+            vec![
+                (1, 2, "`½`"),
+                (2, 1, "`abc`"),
+                (3, 1, "end of line"),
+                (5, 1, "`six`")
+            ],
+            lines
+        );
+        assert!(err.to_string().starts_with(
+            "\
+line 1, column 2: expected an ASCII digit, found `½`
+  |
+1 | a½b7
+  |  ^
+
+line 2, column 1: expected at least one digit, found `abc`"
+        ));
+
+        let vocabulary = DigitVocabulary::default();
+        assert_eq!(
+            Ok(11 + 66),
+            try_calibrate_with_words("x1y\nsix", &vocabulary, CalibrationMode::Strict)
+        );
+
+        // both modes agree on input they both accept
+        for mode in [CalibrationMode::Lenient, CalibrationMode::Strict] {
+            assert_eq!(
+                Ok(281),
+                try_calibrate_with_words(TEST_INPUT_CALIBRATE_WITH_WORDS, &vocabulary, mode)
+            );
+        }
+
+        // numerals inside words are fine
+        let vocabulary = vocabulary.with_word("Ⅷ", 8);
+        assert_eq!(
+            Ok(81),
+            try_calibrate_with_words("Ⅷone", &vocabulary, CalibrationMode::Strict)
+        );
+        let err = try_calibrate_with_words("one٣", &vocabulary, CalibrationMode::Strict);
+        assert_eq!(
+            vec![1],
+            err.unwrap_err()
+                .lines
+                .iter()
+                .map(|err| err.line)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let vocabulary = DigitVocabulary::default();
+        let explanations = explain("eightwo\nabc\n½one٣7x", &vocabulary);
+
+        let token = |start, end, value, kind| {
+            Some(Token {
+                start,
+                end,
+                value,
+                kind,
+            })
+        };
+
+        assert_eq!(3, explanations.len());
+        assert_eq!(token(0, 5, 8, TokenKind::Word), explanations[0].first);
+        assert_eq!(token(4, 7, 2, TokenKind::Word), explanations[0].last);
+        assert_eq!(82, explanations[0].value());
+
+        assert_eq!(
+            (2, None, None),
+            (
+                explanations[1].number,
+                explanations[1].first,
+                explanations[1].last
+            )
+        );
+        assert_eq!(0, explanations[1].value());
+
+        let [first, last] = [explanations[2].first, explanations[2].last].map(Option::unwrap);
+        assert_eq!(
+            ("one", 2..5),
+            (explanations[2].text(&first), first.start..first.end)
+        );
+        assert_eq!(
+            ("7", TokenKind::Digit),
+            (explanations[2].text(&last), last.kind)
+        );
+        assert_eq!(17, explanations[2].value());
+    }
+
+    #[test]
+    fn test_write_explanation() {
+        let mut out = vec![];
+        write_explanation("eightwo\nx", 2, Style::Plain, &mut out).unwrap();
+        assert_eq!(
+            "   1: eightwo  = 82 (first word `eight` at 0..5, last word `two` at 4..7)
+   2: x  = 0 (no digits)
+",
+            String::from_utf8(out).unwrap()
+        );
+
+        let mut out = vec![];
+        write_explanation("aeightwo1", 1, Style::Ansi, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(
+            out.starts_with("   1: aeightwo\x1b[1;36m1\x1b[0m  = 11"),
+            "{out}"
+        );
+
+        let mut out = vec![];
+        write_explanation("aeightwo1\neightwo", 2, Style::Ansi, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert!(
+            lines[0].starts_with("   1: a\x1b[1;32meight\x1b[0mwo\x1b[1;34m1\x1b[0m  = 81"),
+            "{out}"
+        );
+        // the letter both words share is cyan
+        assert!(
+            lines[1].starts_with(
+                "   2: \x1b[1;32meigh\x1b[0m\x1b[1;36mt\x1b[0m\x1b[1;34mwo\x1b[0m  = 82"
+            ),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn test_vocabulary_from_str() {
+        let vocabulary: DigitVocabulary = "# roman numerals and zero
+language roman
+
+case insensitive
+zero = 0
+language = 7"
+            .parse()
+            .unwrap();
+
+        assert_eq!(40, vocabulary.calibration_value("iv zero"));
+        assert_eq!(77, vocabulary.calibration_value("language"));
+        assert_eq!(0, vocabulary.calibration_value("one"));
+
+        let cases: [(&str, usize, &str); 5] = [
+            ("language klingon", 10, "`klingon`"),
+            ("case insensitive sensitive", 18, "`sensitive`"),
+            ("zero 0", 6, "`0`"),
+            ("ten = 10", 7, "`10`"),
+            ("= 3", 1, "`=`"),
+        ];
+
+        for (config, column, found) in cases {
+            let config = format!("language english\n{config}");
+            let err = config
+                .parse::<DigitVocabulary>()
+                .err()
+                .expect("should not parse");
+
+            assert_eq!(2, err.line, "{config}");
+            assert_eq!(column, err.column(), "{config}");
+            assert_eq!(found, err.found, "{config}");
+        }
+    }
+
+    #[test]
+    fn test_readers() {
+        let calibrated = calibrate_reader(TEST_INPUT_CALIBRATE.as_bytes()).unwrap();
+        assert_eq!(142, calibrated);
+
+        let calibrated = calibrate_with_words_reader(
+            TEST_INPUT_CALIBRATE_WITH_WORDS.as_bytes(),
+            &DigitVocabulary::default(),
+        )
+        .unwrap();
+        assert_eq!(281, calibrated);
+    }
+
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    /// Every digit of the line in order, spelled out ones too if `words` is set
+    fn reference_digits(line: &str, words: bool) -> Vec<u32> {
+        line.char_indices()
+            .filter_map(|(index, char)| {
+                let rest = &line[index..];
+                let digit = char.to_digit(10);
+                let word = WORDS
+                    .iter()
+                    .position(|word| words && rest.starts_with(word))
+                    .map(|position| position as u32 + 1);
+
+                digit.or(word)
+            })
+            .collect()
+    }
+
+    fn reference(input: &str, words: bool) -> u64 {
+        input
+            .lines()
+            .map(|line| reference_digits(line, words))
+            .map(|digits| match (digits.first(), digits.last()) {
+                (Some(first), Some(last)) => u64::from(first * 10 + last),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Lines of letters, digits and spelled out digits, which often overlap like `eightwo`
+    fn calibration_input() -> impl Strategy<Value = String> {
+        let fragment = prop_oneof![
+            prop::sample::select(&WORDS[..]).prop_map(String::from),
+            "[a-z]",
+            "[0-9]",
+        ];
+        let line = prop::collection::vec(fragment, 0..12).prop_map(|line| line.concat());
+
+        prop::collection::vec(line, 0..20).prop_map(|lines| lines.join("\n"))
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn test_calibrate_matches_reference(input in calibration_input()) {
+            let expected = reference(&input, false);
+
+            prop_assert_eq!(expected, calibrate(&input));
+            prop_assert_eq!(expected, calibrate_reader(input.as_bytes()).unwrap());
+        }
+
+        /// Any text, with Windows line breaks and characters of every length in UTF-8
+        #[test]
+        fn test_calibrate_bytes_matches_reference(input in "(?s)(\r\n|[0-9a½٣€𝟙]|.){0,100}") {
+            prop_assert_eq!(reference(&input, false), calibrate(&input));
+        }
+
+        #[test]
+        fn test_calibrate_with_words_matches_reference(input in calibration_input()) {
+            let vocabulary = DigitVocabulary::default();
+
+            prop_assert_eq!(reference(&input, true), calibrate_with_words(&input, &vocabulary));
+            prop_assert_eq!(
+                reference(&input, true),
+                calibrate_with_words_reader(input.as_bytes(), &vocabulary).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use crate::error::ParseError;
+use crate::generate::{Generator, Options, Rng};
+use crate::parse::Cursor;
+use crate::solution::Solution;
+use crate::stream::{self, StreamError};
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+
+const LIMITS: (usize, usize, usize) = (12, 13, 14);
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Game>;
+    type Error = ParseError;
+
+    const GENERATE: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| Game::from_line(line).map_err(|err| err.on_line(index + 1)))
+            .collect()
+    }
+
+    fn part1(games: &Self::Input<'_>) -> impl Display {
+        games
+            .iter()
+            .filter(|game| game.fits(LIMITS.0, LIMITS.1, LIMITS.2))
+            .map(|game| game.number)
+            .sum::<usize>()
+    }
+
+    fn part2(games: &Self::Input<'_>) -> impl Display {
+        games
+            .iter()
+            .map(|game| {
+                let (r, g, b) = game.max_cubes();
+                r * g * b
+            })
+            .sum::<usize>()
+    }
+}
+
+/// Streaming variant of both parts that only holds one game in memory at a time
+pub fn solve_reader(reader: impl BufRead) -> Result<(usize, usize), StreamError> {
+    let mut possible = 0;
+    let mut power = 0;
+
+    stream::for_each_line(reader, |number, line| {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        let game = Game::from_line(line).map_err(|err| err.on_line(number))?;
+        let (r, g, b) = game.max_cubes();
+
+        if game.fits(LIMITS.0, LIMITS.1, LIMITS.2) {
+            possible += game.number;
+        }
+        power += r * g * b;
+
+        Ok(())
+    })?;
+
+    Ok((possible, power))
+}
+
+pub struct Game {
+    number: usize,
+    sets: Vec<Set>,
+}
+
+pub struct Set(usize, usize, usize);
+
+impl Game {
+    /// Parses a single line like `Game 1: 3 blue, 4 red; 2 green`, errors point at line 1
+    pub fn from_line(line: &str) -> Result<Game, ParseError> {
+        let mut cursor = Cursor::new(1, line);
+
+        let number = cursor.header("Game")?;
+        let sets = cursor.separated(";", |cursor| {
+            let items = cursor.separated(",", |cursor| {
+                cursor.key_value(
+                    |cursor| {
+                        cursor.spanned(|cursor| {
+                            cursor.labelled("a cube count", Cursor::unsigned::<usize>)
+                        })
+                    },
+                    "",
+                    |cursor| cursor.one_of(&[("red", 0), ("green", 1), ("blue", 2)]),
+                )
+            })?;
+
+            let mut cubes = [0usize; 3];
+            for ((count, span), color) in items {
+                cubes[color] = cubes[color]
+                    .checked_add(count)
+                    .ok_or_else(|| cursor.error(span, "a cube count that fits into the set"))?;
+            }
+
+            Ok(Set(cubes[0], cubes[1], cubes[2]))
+        })?;
+        cursor.end()?;
+
+        Ok(Self { number, sets })
+    }
+
+    fn max_cubes(&self) -> (usize, usize, usize) {
+        let mut max_red = 0;
+        let mut max_green = 0;
+        let mut max_blue = 0;
+
+        for set in self.sets.iter() {
+            if set.0 > max_red {
+                max_red = set.0;
+            }
+
+            if set.1 > max_green {
+                max_green = set.1;
+            }
+
+            if set.2 > max_blue {
+                max_blue = set.2;
+            }
+        }
+
+        (max_red, max_green, max_blue)
+    }
+
+    fn fits(&self, red: usize, green: usize, blue: usize) -> bool {
+        let (r, g, b) = self.max_cubes();
+        r <= red && g <= green && b <= blue
+    }
+}
+
+/// `size` games of up to six sets, `density` is how likely a set contains cubes of each color
+fn generate(rng: &mut Rng, options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let games = options.size.unwrap_or(100);
+    let density = options.density.unwrap_or(0.6);
+    let mut colors = ["red", "green", "blue"];
+
+    for number in 1..=games {
+        let mut sets = vec![];
+
+        for _ in 0..rng.between(1, 6) {
+            rng.shuffle(&mut colors);
+
+            let mut cubes = vec![];
+            for color in colors {
+                if rng.chance(density) {
+                    cubes.push(format!("{} {color}", rng.between(1, 20)));
+                }
+            }
+
+            // the parser expects at least one color per set
+            if cubes.is_empty() {
+                cubes.push(format!("{} {}", rng.between(1, 20), colors[0]));
+            }
+
+            sets.push(cubes.join(", "));
+        }
+
+        writeln!(out, "Game {number}: {}", sets.join("; "))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_2023_02 {
+    use super::{solve_reader, Day02, Game, LIMITS};
+    use crate::error::ParseError;
+    use crate::solution::Solution;
+    use crate::stream::StreamError;
+    use proptest::prelude::*;
+
+    const TEST_LINES: [(&str, (usize, usize, usize)); 5] = [
+        (
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            (4, 2, 6),
+        ),
+        (
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+            (1, 3, 4),
+        ),
+        (
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+            (20, 13, 6),
+        ),
+        (
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+            (14, 3, 15),
+        ),
+        (
+            "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+            (6, 3, 2),
+        ),
+    ];
+
+    #[test]
+    fn test_parsing_lines() {
+        for (line, (expected_red, expected_green, expected_blue)) in TEST_LINES.iter() {
+            let game = Game::from_line(line).expect("should parse");
+
+            let (r, g, b) = game.max_cubes();
+
+            assert_eq!(
+                *expected_red, r,
+                "max red cubes for line: {} :: should be {} but is {}",
+                line, expected_red, r
+            );
+            assert_eq!(
+                *expected_green, g,
+                "max green cubes for line: {} :: should be {} but is {}",
+                line, expected_green, g
+            );
+            assert_eq!(
+                *expected_blue, b,
+                "max blue cubes for line: {} :: should be {} but is {}",
+                line, expected_blue, b
+            );
+        }
+    }
+
+    const TEST_LINES_FIT: [(&str, (usize, usize, usize), bool); 5] = [
+        (TEST_LINES[0].0, (12, 13, 14), true),
+        (TEST_LINES[1].0, (12, 13, 14), true),
+        (TEST_LINES[2].0, (12, 13, 14), false),
+        (TEST_LINES[3].0, (12, 13, 14), false),
+        (TEST_LINES[4].0, (12, 13, 14), true),
+    ];
+    const TEST_LINES_POSSIBLE_ID_SUM: usize = 8;
+
+    #[test]
+    fn test_parsed_lines_fit() {
+        let mut sum = 0;
+
+        for (line, (red_cubes, green_cubes, blue_cubes), expect_fits) in TEST_LINES_FIT.iter() {
+            let game = Game::from_line(line).expect("should parse");
+
+            let (r, g, b) = game.max_cubes();
+            let fits = game.fits(*red_cubes, *green_cubes, *blue_cubes);
+
+            assert_eq!(
+                *expect_fits, fits,
+                "Game {} ({}, {}, {}) fits within ({}, {}, {})? Result: {} but expected: {}",
+                game.number, r, g, b, red_cubes, green_cubes, blue_cubes, fits, expect_fits
+            );
+
+            if fits {
+                sum += game.number;
+            }
+        }
+
+        assert_eq!(TEST_LINES_POSSIBLE_ID_SUM, sum);
+    }
+
+    #[test]
+    fn test_solution() {
+        let input = TEST_LINES.map(|(line, _)| line).join("\n");
+        let games = Day02::parse(&input).expect("should parse");
+
+        assert_eq!("8", Day02::part1(&games).to_string());
+        assert_eq!("2286", Day02::part2(&games).to_string());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases: [(&str, usize, &str); 7] = [
+            ("Game 1 3 blue", 8, "`3`"),
+            ("Gme 1: 3 blue", 1, "`Gme`"),
+            ("Game one: 3 blue", 6, "`one`"),
+            ("Game 1: 3 blue, x red", 17, "`x`"),
+            ("Game 1: 3 blue; 4 purple", 19, "`purple`"),
+            ("Game 1: 3 blue 4 red", 16, "`4`"),
+            ("Game 1: 18446744073709551615 red, 1 red", 35, "`1`"),
+        ];
+
+        for (line, column, found) in cases {
+            let err: ParseError = Game::from_line(line).err().expect("should not parse");
+
+            assert_eq!(column, err.column(), "{line}");
+            assert_eq!(found, err.found, "{line}");
+        }
+
+        let input = format!("{}\nGame 2: 1 red, 2 blu", TEST_LINES[0].0);
+        let err = Day02::parse(&input).err().expect("should not parse");
+        assert_eq!(2, err.line);
+        assert_eq!("`blu`", err.found);
+    }
+
+    #[test]
+    fn test_solve_reader() {
+        let input = TEST_LINES.map(|(line, _)| line).join("\n");
+        assert_eq!((8, 2286), solve_reader(input.as_bytes()).unwrap());
+
+        let input = format!("{input}\nGame 6: 1 red, 2 blu");
+        let err = solve_reader(input.as_bytes()).err().unwrap();
+        assert!(matches!(err, StreamError::Parse(err) if err.line == 6));
+    }
+
+    const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+    /// A game as the numbers of every set's cubes and their colors
+    type GameData = (usize, Vec<Vec<(usize, usize)>>);
+
+    fn render((number, sets): &GameData) -> String {
+        let sets: Vec<String> = sets
+            .iter()
+            .map(|set| {
+                let cubes: Vec<String> = set
+                    .iter()
+                    .map(|(count, color)| format!("{count} {}", COLORS[*color]))
+                    .collect();
+                cubes.join(", ")
+            })
+            .collect();
+
+        format!("Game {number}: {}", sets.join("; "))
+    }
+
+    /// Sums of the ids of possible games and of the powers of every game
+    fn reference(games: &[GameData]) -> (usize, usize) {
+        let limits = [LIMITS.0, LIMITS.1, LIMITS.2];
+        let mut possible = 0;
+        let mut power = 0;
+
+        for (number, sets) in games {
+            let mut fits = true;
+            let mut maximum = [0; 3];
+
+            for set in sets {
+                for color in 0..3 {
+                    let count: usize = set
+                        .iter()
+                        .filter(|(_, cube_color)| *cube_color == color)
+                        .map(|(count, _)| count)
+                        .sum();
+
+                    fits &= count <= limits[color];
+                    maximum[color] = maximum[color].max(count);
+                }
+            }
+
+            if fits {
+                possible += number;
+            }
+            power += maximum[0] * maximum[1] * maximum[2];
+        }
+
+        (possible, power)
+    }
+
+    fn games() -> impl Strategy<Value = Vec<GameData>> {
+        let set = prop::collection::vec((0..20usize, 0..3usize), 1..5);
+        let game = (1..200usize, prop::collection::vec(set, 1..6));
+
+        prop::collection::vec(game, 0..20)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn test_solution_matches_reference(games in games()) {
+            let input: Vec<String> = games.iter().map(render).collect();
+            let input = input.join("\n");
+            let (possible, power) = reference(&games);
+
+            let parsed = Day02::parse(&input).unwrap();
+            prop_assert_eq!(possible.to_string(), Day02::part1(&parsed).to_string());
+            prop_assert_eq!(power.to_string(), Day02::part2(&parsed).to_string());
+            prop_assert_eq!((possible, power), solve_reader(input.as_bytes()).unwrap());
+        }
+    }
+}
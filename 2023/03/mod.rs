@@ -0,0 +1,543 @@
+use crate::error::ParseError;
+use crate::generate::{Generator, Options, Rng};
+use crate::grid::{Grid, GridError, Point};
+use crate::parse::number_from_digits;
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Schematic;
+    type Error = ParseError;
+
+    const GENERATE: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Schematic::from_str(input)
+    }
+
+    fn part1(schematic: &Self::Input<'_>) -> impl Display {
+        schematic.sum()
+    }
+
+    fn part2(schematic: &Self::Input<'_>) -> impl Display {
+        schematic.gear_part_sum()
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum Value {
+    Empty,
+    Symbol,
+    Gear,
+    Digit(usize),
+}
+
+impl Value {
+    fn is_symbol(&self) -> bool {
+        *self == Value::Symbol || *self == Value::Gear
+    }
+
+    fn from_char(char: char) -> Self {
+        match char {
+            '.' => Value::Empty,
+            '*' => Value::Gear,
+            char => match char.to_digit(10) {
+                Some(digit) => Value::Digit(digit as usize),
+                None => Value::Symbol,
+            },
+        }
+    }
+}
+
+pub struct Schematic {
+    values: Grid<Value>,
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // spaces would count as symbols, so surrounding whitespace is not part of the schematic
+        let rows: Vec<&str> = s.lines().map(str::trim).collect();
+        let values = Grid::parse(&rows.join("\n"), Value::from_char).map_err(|err| match err {
+            GridError::Empty => ParseError::new(1, "", 0..0, "a schematic"),
+            GridError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => {
+                let source = s.lines().nth(line - 1).unwrap_or_default();
+                let indent = source.len() - source.trim_start().len();
+                let row = rows[line - 1];
+                // the grid counts columns in chars, errors point at bytes
+                let start = row
+                    .char_indices()
+                    .nth(expected.min(found))
+                    .map_or(row.len(), |(start, _)| start);
+
+                ParseError::new(
+                    line,
+                    source,
+                    indent + start..indent + row.len(),
+                    format!("{expected} columns"),
+                )
+                .with_found(format!("{found} columns"))
+            }
+        })?;
+
+        Ok(Schematic { values })
+    }
+}
+
+#[derive(Copy, Clone)]
+enum FindAdjacentValueSearchParam {
+    IsSymbol,
+    IsDigit,
+}
+
+impl Schematic {
+    fn find_part_numbers(&self) -> Vec<usize> {
+        let mut numbers = vec![];
+
+        for (row_index, row) in self.values.rows().enumerate() {
+            let mut digits = vec![];
+
+            for (column_index, value) in row.iter().enumerate() {
+                if let Value::Digit(digit) = value {
+                    let column_start = column_index - digits.len();
+
+                    digits.push(*digit);
+
+                    if self.is_next_number(row_index, column_index) {
+                        continue;
+                    }
+
+                    // this number has no adjacent symbols so we can ignore it
+                    if !self.has_adjacent_symbol_range(row_index, column_start, column_index) {
+                        digits.clear();
+                        continue;
+                    }
+
+                    numbers.push(number_from_digits(&digits));
+                    digits.clear();
+                    continue;
+                }
+            }
+        }
+
+        numbers
+    }
+
+    fn find_gear_ratios(&self) -> Vec<usize> {
+        let mut numbers = vec![];
+
+        for (point, value) in self.values.iter() {
+            if *value != Value::Gear {
+                continue;
+            }
+
+            let digits = self.find_adjacent_symbols(
+                point.row,
+                point.column,
+                FindAdjacentValueSearchParam::IsDigit,
+            );
+
+            let mut found_map = HashMap::new();
+
+            for (digit_row, digit_col, _) in digits.iter() {
+                let (num, key) = self.find_number_from_digit(*digit_row, *digit_col);
+
+                if found_map.contains_key(&key) {
+                    continue;
+                }
+                found_map.insert(key, num);
+            }
+
+            if found_map.len() < 2 {
+                continue;
+            }
+
+            numbers.push(found_map.values().product());
+        }
+
+        numbers
+    }
+
+    fn find_number_from_digit(&self, row: usize, column: usize) -> (usize, (usize, usize, usize)) {
+        // find actual start
+        let mut col_start = column;
+        if column > 0 {
+            for col in (0..=column).rev() {
+                let value = self.get(row, col).unwrap();
+
+                if let Value::Digit(_) = value {
+                    col_start = col;
+                    continue;
+                }
+
+                break;
+            }
+        }
+
+        // find actual end
+        let mut col_end = column;
+        if column < self.values.width() {
+            for col in column..self.values.width() {
+                let value = self.get(row, col).unwrap();
+
+                if let Value::Digit(_) = value {
+                    col_end = col;
+                    continue;
+                }
+
+                break;
+            }
+        }
+
+        let mut digits = vec![];
+        for col in col_start..=col_end {
+            if let Value::Digit(digit) = self.get(row, col).unwrap() {
+                digits.push(digit);
+            }
+        }
+
+        (number_from_digits(&digits), (row, col_start, col_end))
+    }
+
+    fn has_adjacent_symbol_range(
+        &self,
+        row: usize,
+        column_start: usize,
+        column_end: usize,
+    ) -> bool {
+        !self
+            .find_adjacent_symbols_range(
+                row,
+                column_start,
+                column_end,
+                FindAdjacentValueSearchParam::IsSymbol,
+            )
+            .is_empty()
+    }
+
+    fn find_adjacent_symbols_range(
+        &self,
+        row: usize,
+        column_start: usize,
+        column_end: usize,
+        search_value: FindAdjacentValueSearchParam,
+    ) -> Vec<(usize, usize, Value)> {
+        (column_start..=column_end)
+            .flat_map(|col| self.find_adjacent_symbols(row, col, search_value))
+            .collect()
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<Value> {
+        self.values.get((row, column)).copied()
+    }
+
+    fn find_adjacent_symbols(
+        &self,
+        row: usize,
+        column: usize,
+        search_value: FindAdjacentValueSearchParam,
+    ) -> Vec<(usize, usize, Value)> {
+        let mut values = vec![];
+
+        for Point { row, column } in self.values.neighbours8((row, column)) {
+            let value = self.values[(row, column)];
+
+            match search_value {
+                FindAdjacentValueSearchParam::IsSymbol => {
+                    if value.is_symbol() {
+                        values.push((row, column, value));
+                    }
+                }
+                FindAdjacentValueSearchParam::IsDigit => {
+                    if let Value::Digit(_) = value {
+                        values.push((row, column, value));
+                    }
+                }
+            };
+        }
+
+        values
+    }
+
+    fn is_next_number(&self, row: usize, column: usize) -> bool {
+        if column + 1 >= self.values.width() {
+            return false;
+        }
+
+        let next = self.values[(row, column + 1)];
+
+        !next.is_symbol() && next != Value::Empty
+    }
+
+    fn sum(&self) -> usize {
+        self.find_part_numbers().iter().sum()
+    }
+
+    fn gear_part_sum(&self) -> usize {
+        self.find_gear_ratios().iter().sum()
+    }
+}
+
+/// Square schematic with a side of `size` cells, `density` is the share of cells that are gears
+///
+/// Numbers have at most three digits and are always followed by a `.`, like in real inputs.
+fn generate(rng: &mut Rng, options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    const SYMBOLS: [char; 9] = ['#', '$', '%', '&', '+', '-', '/', '=', '@'];
+
+    let side = options.size.unwrap_or(140);
+    let gears = options.density.unwrap_or(0.02);
+    let mut row = String::with_capacity(side + 1);
+
+    for _ in 0..side {
+        row.clear();
+
+        while row.len() < side {
+            if rng.chance(gears) {
+                row.push('*');
+            } else if rng.chance(0.02) {
+                row.push(*rng.pick(&SYMBOLS));
+            } else if rng.chance(0.1) {
+                let digits = rng.between(1, 3).min(side - row.len());
+                row.push_str(
+                    &rng.between(10usize.pow(digits as u32 - 1), 999).to_string()[..digits],
+                );
+
+                if row.len() < side {
+                    row.push('.');
+                }
+            } else {
+                row.push('.');
+            }
+        }
+
+        writeln!(out, "{row}")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_2023_03 {
+    use super::{Schematic, Value};
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    const TEST_SIMPLIFIED_SCHEMATIC: &str = "\
+12..
+$..1
+8...";
+
+    #[test]
+    fn test_parsing_simple_schematic() {
+        let schematic = Schematic::from_str(TEST_SIMPLIFIED_SCHEMATIC).expect("should parse");
+
+        assert_eq!(4, schematic.values.width());
+        assert_eq!(3, schematic.values.height());
+
+        let expected_values = [
+            // 12..
+            [Value::Digit(1), Value::Digit(2), Value::Empty, Value::Empty],
+            // $...
+            [Value::Symbol, Value::Empty, Value::Empty, Value::Digit(1)],
+            // 8...
+            [Value::Digit(8), Value::Empty, Value::Empty, Value::Empty],
+        ];
+
+        for (row_index, row) in schematic.values.rows().enumerate() {
+            for (column_index, value) in row.iter().enumerate() {
+                assert_eq!(expected_values[row_index][column_index], *value);
+            }
+        }
+
+        assert_eq!(20, schematic.sum());
+    }
+
+    const TEST_SCHEMATICS: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn test_parsing_example() {
+        let schematic = Schematic::from_str(TEST_SCHEMATICS).expect("should parse");
+        assert_eq!(4361, schematic.sum());
+    }
+
+    #[test]
+    fn test_parsing_gear_part_example() {
+        let schematic = Schematic::from_str(TEST_SCHEMATICS).expect("should parse");
+        assert_eq!(467835, schematic.gear_part_sum());
+    }
+
+    const TEST_SCHEMATIC_GEAR_EXHAUSTIVE: &str = "\
+4*4...2*
+.......2
+........
+2*...111
+11....*.
+....2.11
+...2*2..
+....2...";
+
+    #[test]
+    fn test_parsing_gear_part_example_exhaustive() {
+        let schematic = Schematic::from_str(TEST_SCHEMATIC_GEAR_EXHAUSTIVE).expect("should parse");
+        assert_eq!(1279, schematic.gear_part_sum());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Schematic::from_str("").err().expect("should not parse");
+        assert_eq!(1, err.line);
+
+        let err = Schematic::from_str("467..\n...*..\n..35.")
+            .err()
+            .expect("should not parse");
+        assert_eq!(2, err.line);
+        assert_eq!(6, err.column());
+        assert_eq!("5 columns", err.expected);
+        assert_eq!("6 columns", err.found);
+
+        let err = Schematic::from_str("4ä.\n4ä..")
+            .err()
+            .expect("should not parse");
+        assert_eq!((2, 5), (err.line, err.column()));
+        assert_eq!(".", err.snippet());
+
+        let err = Schematic::from_str("467..\n  ...*..  \n")
+            .err()
+            .expect("should not parse");
+        assert_eq!((2, 8), (err.line, err.column()));
+        assert_eq!(".", err.snippet());
+    }
+
+    #[test]
+    fn test_parse_whitespace() {
+        let padded: String = TEST_SCHEMATICS
+            .lines()
+            .map(|line| format!(" {line} \n  \n"))
+            .collect();
+        let schematic = Schematic::from_str(&padded).expect("should parse");
+
+        assert_eq!(
+            (10, 10),
+            (schematic.values.width(), schematic.values.height())
+        );
+        assert_eq!(4361, schematic.sum());
+        assert_eq!(467835, schematic.gear_part_sum());
+
+        let schematic = Schematic::from_str("467..114.. \n...*......\n").expect("should parse");
+        assert_eq!(467, schematic.sum());
+    }
+
+    /// Every number of the schematic with its row and column range
+    fn reference_numbers(rows: &[Vec<char>]) -> Vec<(usize, usize, usize, usize)> {
+        let mut numbers = vec![];
+
+        for (row, cells) in rows.iter().enumerate() {
+            let mut column = 0;
+
+            while column < cells.len() {
+                if !cells[column].is_ascii_digit() {
+                    column += 1;
+                    continue;
+                }
+
+                let start = column;
+                while column < cells.len() && cells[column].is_ascii_digit() {
+                    column += 1;
+                }
+
+                let number: String = cells[start..column].iter().collect();
+                numbers.push((number.parse().unwrap(), row, start, column - 1));
+            }
+        }
+
+        numbers
+    }
+
+    fn is_adjacent(row: usize, column: usize, number: &(usize, usize, usize, usize)) -> bool {
+        let (_, number_row, start, end) = *number;
+
+        row.abs_diff(number_row) <= 1 && column + 1 >= start && column <= end + 1
+    }
+
+    /// Sums of the part numbers and of the gear ratios, found by checking every cell against
+    /// every number
+    fn reference(rows: &[Vec<char>]) -> (usize, usize) {
+        let numbers = reference_numbers(rows);
+        let mut part_sum = 0;
+        let mut gear_sum = 0;
+
+        for number in numbers.iter() {
+            let is_part = rows.iter().enumerate().any(|(row, cells)| {
+                cells.iter().enumerate().any(|(column, cell)| {
+                    *cell != '.' && !cell.is_ascii_digit() && is_adjacent(row, column, number)
+                })
+            });
+
+            if is_part {
+                part_sum += number.0;
+            }
+        }
+
+        for (row, cells) in rows.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                let adjacent: Vec<usize> = numbers
+                    .iter()
+                    .filter(|number| *cell == '*' && is_adjacent(row, column, number))
+                    .map(|number| number.0)
+                    .collect();
+
+                if adjacent.len() >= 2 {
+                    gear_sum += adjacent.iter().product::<usize>();
+                }
+            }
+        }
+
+        (part_sum, gear_sum)
+    }
+
+    /// Schematics narrow enough that the product of all numbers around a gear fits into a usize
+    fn schematics() -> impl Strategy<Value = Vec<Vec<char>>> {
+        let cell = prop_oneof![
+            5 => Just('.'),
+            3 => prop::char::range('0', '9'),
+            1 => Just('*'),
+            1 => prop::sample::select(vec!['#', '$', '+', '/', '=', '@']),
+        ];
+
+        (1..=6usize, 1..=16usize).prop_flat_map(move |(width, height)| {
+            prop::collection::vec(prop::collection::vec(cell.clone(), width), height)
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn test_schematic_matches_reference(rows in schematics()) {
+            let input: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+            let schematic = Schematic::from_str(&input.join("\n")).unwrap();
+
+            prop_assert_eq!(reference(&rows), (schematic.sum(), schematic.gear_part_sum()));
+        }
+    }
+}
@@ -0,0 +1,462 @@
+use crate::collections::merge_counts;
+use crate::error::ParseError;
+use crate::generate::{Generator, Options, Rng};
+use crate::parse::Cursor;
+use crate::solution::Solution;
+use crate::stream::{self, StreamError};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Game;
+    type Error = ParseError;
+
+    const GENERATE: Option<Generator> = Some(generate);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Game::from_str(input)
+    }
+
+    fn part1(game: &Self::Input<'_>) -> impl Display {
+        game.total_points()
+    }
+
+    fn part2(game: &Self::Input<'_>) -> impl Display {
+        game.scratch_cards_total()
+    }
+}
+
+/// Streaming variant of both parts that only holds one card in memory at a time
+///
+/// Cards have to be numbered consecutively from 1, instead of keeping every card around this only
+/// tracks how many copies the following cards have won so far.
+pub fn solve_reader(reader: impl BufRead) -> Result<(u64, u64), StreamError> {
+    let mut points = 0u64;
+    let mut cards = 0;
+    let mut copies: VecDeque<u64> = VecDeque::new();
+    let mut next_id = 1;
+
+    stream::for_each_line(reader, |number, line| {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        let card = Card::from_str(line).map_err(|err| err.on_line(number))?;
+
+        if card.id != next_id {
+            let err = ParseError::new(number, line, 0..line.len(), format!("card {next_id}"))
+                .with_found(format!("card {}", card.id));
+            return Err(err.into());
+        }
+        next_id += 1;
+
+        let count = 1 + copies.pop_front().unwrap_or(0);
+        let matches = card.user_winning_numbers().len();
+
+        if copies.len() < matches {
+            copies.resize(matches, 0);
+        }
+        for won in copies.iter_mut().take(matches) {
+            *won += count;
+        }
+
+        points = points.saturating_add(card.points());
+        cards += count;
+
+        Ok(())
+    })?;
+
+    Ok((points, cards))
+}
+
+pub struct Game {
+    cards: HashMap<u32, Card>,
+}
+
+impl Game {
+    fn total_points(&self) -> u64 {
+        self.cards
+            .values()
+            .map(|card| card.points())
+            .fold(0, u64::saturating_add)
+    }
+
+    fn scratch_cards(&self) -> HashMap<u32, u64> {
+        let mut cache: HashMap<u32, HashMap<u32, u64>> = HashMap::new();
+        let mut cards = HashMap::new();
+
+        for card_id in self.cards.keys() {
+            let results = if cache.contains_key(card_id) {
+                cache.get(card_id).unwrap()
+            } else {
+                let results = self.scratch_cards_for(*card_id, &mut cache);
+                cache.insert(*card_id, results);
+                cache.get(card_id).unwrap()
+            };
+
+            merge_counts(&mut cards, results);
+        }
+
+        cards
+    }
+
+    fn scratch_cards_for(
+        &self,
+        id: u32,
+        cache: &mut HashMap<u32, HashMap<u32, u64>>,
+    ) -> HashMap<u32, u64> {
+        let mut cards = HashMap::new();
+
+        let card = self.cards.get(&id);
+
+        if card.is_none() {
+            return cards;
+        }
+
+        let card = card.unwrap();
+
+        cards.insert(card.id, 1);
+
+        let count = card.user_winning_numbers().len();
+
+        let new_ids: Vec<u32> = (1..=count).map(|num| id + (num as u32)).collect();
+
+        for new_id in new_ids.iter() {
+            let results = if cache.contains_key(new_id) {
+                cache.get(new_id).unwrap()
+            } else {
+                let results = self.scratch_cards_for(*new_id, cache);
+                cache.insert(*new_id, results);
+                cache.get(new_id).unwrap()
+            };
+
+            merge_counts(&mut cards, results);
+        }
+
+        cards
+    }
+
+    fn scratch_cards_total(&self) -> u64 {
+        self.scratch_cards().values().sum()
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cards = HashMap::new();
+
+        for (index, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let card = Card::from_str(line).map_err(|err| err.on_line(index + 1))?;
+            cards.insert(card.id, card);
+        }
+
+        Ok(Game { cards })
+    }
+}
+
+pub struct Card {
+    id: u32,
+    winning_numbers: Vec<u32>,
+    user_numbers: Vec<u32>,
+}
+
+impl Card {
+    fn user_winning_numbers(&self) -> Vec<u32> {
+        self.user_numbers
+            .iter()
+            .filter(|num| self.winning_numbers.contains(num))
+            .copied()
+            .collect()
+    }
+
+    /// One point for the first match, doubled for every further one, saturating at `u64::MAX`
+    /// from 65 matches on
+    fn calculate_points_for_matches(&self, number_matches: u32) -> u64 {
+        match number_matches {
+            0 => 0,
+            num => 1u64.checked_shl(num - 1).unwrap_or(u64::MAX),
+        }
+    }
+
+    fn points(&self) -> u64 {
+        let matches = self.user_winning_numbers();
+        self.calculate_points_for_matches(matches.len() as u32)
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cursor = Cursor::new(1, s);
+
+        let id = cursor.header("Card")?;
+        let winning_numbers = cursor.many_until("|", Cursor::unsigned)?;
+        cursor.literal("|")?;
+        let user_numbers = cursor.many(Cursor::unsigned)?;
+
+        Ok(Card {
+            id,
+            winning_numbers,
+            user_numbers,
+        })
+    }
+}
+
+/// Most copies a generated card may end up with, keeps the part 2 total of any deck within a u64
+const MAX_COPIES: u64 = u32::MAX as u64;
+
+/// Deck of `size` cards with 10 winning numbers and 25 numbers you have, `density` is how likely
+/// you have each winning number
+///
+/// Like in real inputs no card wins copies of cards past the end of the deck. Part 2 grows
+/// exponentially with the number of matches, so cards drop matches that would give a later card
+/// more than `MAX_COPIES` copies.
+fn generate(rng: &mut Rng, options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let cards = options.size.unwrap_or(200);
+    let density = options.density.unwrap_or(0.3);
+    let width = cards.to_string().len();
+    let mut pool: Vec<u32> = (1..100).collect();
+
+    let join = |numbers: &[u32]| {
+        let numbers: Vec<String> = numbers
+            .iter()
+            .map(|number| format!("{number:>2}"))
+            .collect();
+        numbers.join(" ")
+    };
+
+    // copies the following cards have won so far, like in `solve_reader`
+    let mut copies: VecDeque<u64> = VecDeque::new();
+
+    for id in 1..=cards {
+        rng.shuffle(&mut pool);
+
+        let count = 1 + copies.pop_front().unwrap_or(0);
+        let (winning, rest) = pool.split_at(10);
+        let mut numbers: Vec<u32> = winning
+            .iter()
+            .take(cards - id)
+            .copied()
+            .filter(|_| rng.chance(density))
+            .collect();
+
+        let allowed = (0..numbers.len())
+            .take_while(|index| copies.get(*index).unwrap_or(&0) + count <= MAX_COPIES)
+            .count();
+        numbers.truncate(allowed);
+
+        if copies.len() < numbers.len() {
+            copies.resize(numbers.len(), 0);
+        }
+        for won in copies.iter_mut().take(numbers.len()) {
+            *won += count;
+        }
+
+        numbers.extend(&rest[..25 - numbers.len()]);
+        rng.shuffle(&mut numbers);
+
+        writeln!(
+            out,
+            "Card {id:>width$}: {} | {}",
+            join(winning),
+            join(&numbers)
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_2023_04 {
+    use super::{solve_reader, Card, Game};
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    const TEST_EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_parse_example() {
+        let game = Game::from_str(TEST_EXAMPLE).expect("should exist");
+
+        let test_points = HashMap::from([(1, 8), (2, 2), (3, 2), (4, 1), (5, 0), (6, 0)]);
+        let test_total_points: u64 = test_points.values().sum();
+
+        for (id, card) in game.cards.iter() {
+            assert_eq!(test_points.get(id).unwrap().clone(), card.points());
+        }
+
+        assert_eq!(test_total_points, game.total_points());
+    }
+
+    #[test]
+    fn test_many_matches() {
+        let card = |id: u32, matches: u32| {
+            let numbers: Vec<_> = (1..=matches).map(|number| number.to_string()).collect();
+            format!("Card {id}: {0} | {0}", numbers.join(" "))
+        };
+
+        let points = [
+            (0, 0),
+            (1, 1),
+            (64, 1 << 63),
+            (65, u64::MAX),
+            (100, u64::MAX),
+        ];
+        for (matches, expected) in points {
+            let card = Card::from_str(&card(1, matches)).expect("should parse");
+            assert_eq!(expected, card.points(), "{matches} matches");
+        }
+
+        let input = format!("{}\n{}", card(1, 64), card(2, 65));
+        let game = Game::from_str(&input).expect("should parse");
+        assert_eq!(u64::MAX, game.total_points());
+        assert_eq!(u64::MAX, solve_reader(input.as_bytes()).unwrap().0);
+    }
+
+    #[test]
+    fn test_parse_example_part2() {
+        let game = Game::from_str(TEST_EXAMPLE).expect("should exist");
+
+        let scratch_card_counts = game.scratch_cards();
+
+        let expected_counts = HashMap::from([(1, 1), (2, 2), (3, 4), (4, 8), (5, 14), (6, 1)]);
+        let expected_total = 30;
+
+        for (id, num) in scratch_card_counts.iter() {
+            let expected = expected_counts.get(id).unwrap();
+            assert_eq!(expected, num, "ID: {id} expected: {expected} but got {num}");
+        }
+
+        assert_eq!(expected_total, game.scratch_cards_total());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Card::from_str("Card 1 41 48 | 83 86").err().unwrap();
+        assert_eq!(8, err.column());
+        assert_eq!("`:`", err.expected);
+
+        let err = Card::from_str("Crad 1: 41 48 | 83 86").err().unwrap();
+        assert_eq!(1, err.column());
+        assert_eq!("`Crad`", err.found);
+
+        let err = Card::from_str("Card x: 41 48 | 83 86").err().unwrap();
+        assert_eq!(6, err.column());
+        assert_eq!("a card number", err.expected);
+
+        let err = Card::from_str("Card 1: 41 48 83 86").err().unwrap();
+        assert_eq!("`|`", err.expected);
+        assert_eq!("end of line", err.found);
+
+        let err = Card::from_str("Card 1: 41 4b | 83 86").err().unwrap();
+        assert_eq!(12, err.column());
+        assert_eq!("`4b`", err.found);
+
+        let err = Card::from_str("Card 1: 41 | 83 | 86").err().unwrap();
+        assert_eq!(17, err.column());
+
+        let err = Game::from_str("\nCard 1: 41 | 83\nCard 2: 13 | x")
+            .err()
+            .unwrap();
+        assert_eq!(3, err.line);
+        assert_eq!(14, err.column());
+    }
+
+    #[test]
+    fn test_solve_reader() {
+        assert_eq!((13, 30), solve_reader(TEST_EXAMPLE.as_bytes()).unwrap());
+
+        let input = TEST_EXAMPLE.replace("Card 4:", "Card 7:");
+        let err = solve_reader(input.as_bytes()).err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("line 4, column 1: expected card 4, found card 7"));
+    }
+
+    /// Winning numbers and the numbers you have of every card
+    type CardData = (BTreeSet<u32>, BTreeSet<u32>);
+
+    fn render(cards: &[CardData]) -> String {
+        let join = |numbers: &BTreeSet<u32>| {
+            let numbers: Vec<String> = numbers.iter().map(|n| format!("{n:>2}")).collect();
+            numbers.join(" ")
+        };
+
+        let lines: Vec<String> = cards
+            .iter()
+            .enumerate()
+            .map(|(index, (winning, numbers))| {
+                format!("Card {}: {} | {}", index + 1, join(winning), join(numbers))
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+
+    /// Total points and the number of scratch cards, found by scratching every single copy
+    fn reference(cards: &[CardData]) -> (u64, u64) {
+        let matches: Vec<usize> = cards
+            .iter()
+            .map(|(winning, numbers)| numbers.iter().filter(|n| winning.contains(n)).count())
+            .collect();
+
+        let points = matches
+            .iter()
+            .filter(|count| **count > 0)
+            .map(|count| 2u64.pow(*count as u32 - 1))
+            .sum();
+
+        let mut pile: Vec<usize> = (0..cards.len()).collect();
+        let mut scratched = 0;
+
+        while let Some(card) = pile.pop() {
+            scratched += 1;
+            pile.extend((card + 1..=card + matches[card]).filter(|won| *won < cards.len()));
+        }
+
+        (points, scratched)
+    }
+
+    fn cards() -> impl Strategy<Value = Vec<CardData>> {
+        let card = (
+            prop::collection::btree_set(1..40u32, 0..6),
+            prop::collection::btree_set(1..40u32, 0..9),
+        );
+
+        prop::collection::vec(card, 0..=12)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn test_game_matches_reference(cards in cards()) {
+            let input = render(&cards);
+            let (points, scratched) = reference(&cards);
+            let game = Game::from_str(&input).unwrap();
+
+            prop_assert_eq!(points, game.total_points());
+            prop_assert_eq!(scratched, game.scratch_cards_total());
+            prop_assert_eq!((points, scratched), solve_reader(input.as_bytes()).unwrap());
+        }
+    }
+}
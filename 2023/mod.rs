@@ -0,0 +1,8 @@
+#[path = "01/mod.rs"]
+pub mod day01;
+#[path = "02/mod.rs"]
+pub mod day02;
+#[path = "03/mod.rs"]
+pub mod day03;
+#[path = "04/mod.rs"]
+pub mod day04;
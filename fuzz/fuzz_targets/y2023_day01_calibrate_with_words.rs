@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent_of_code::y2023::day01::{calibrate_with_words, DigitVocabulary};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    calibrate_with_words(input, &DigitVocabulary::default());
+});
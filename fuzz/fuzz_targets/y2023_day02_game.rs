@@ -0,0 +1,11 @@
+#![no_main]
+
+use advent_of_code::y2023::day02::Game;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|line: &str| {
+    if let Err(err) = Game::from_line(line) {
+        // rendering the caret diagram slices the line at the error span
+        err.to_string();
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use advent_of_code::y2023::day03::Schematic;
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|input: &str| {
+    if let Err(err) = Schematic::from_str(input) {
+        err.to_string();
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use advent_of_code::y2023::day04::{Card, Game};
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|input: &str| {
+    if let Some(line) = input.lines().next() {
+        if let Err(err) = Card::from_str(line) {
+            err.to_string();
+        }
+    }
+
+    if let Err(err) = Game::from_str(input) {
+        err.to_string();
+    }
+});
@@ -0,0 +1,259 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Known correct and wrong answers of a single day, keyed by the name of the input file they
+/// belong to
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    inputs: BTreeMap<String, InputAnswers>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    /// Answers that were submitted and rejected
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<WrongAnswer>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WrongAnswer {
+    pub part: u8,
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// What is already known about an answer before submitting it
+#[derive(Debug, PartialEq, Eq)]
+pub enum Judgement {
+    Correct,
+    /// The answer differs from the known correct answer
+    Mismatch(String),
+    /// The answer was rejected before, or lies beyond an answer that was too high or too low
+    Wrong(Option<Hint>),
+    Unknown,
+}
+
+impl InputAnswers {
+    fn part(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn part_mut(&mut self, part: u8) -> Option<&mut Option<String>> {
+        match part {
+            1 => Some(&mut self.part1),
+            2 => Some(&mut self.part2),
+            _ => None,
+        }
+    }
+
+    fn judge(&self, part: u8, answer: &str) -> Judgement {
+        if let Some(correct) = self.part(part) {
+            if correct == answer {
+                return Judgement::Correct;
+            }
+
+            return Judgement::Mismatch(correct.clone());
+        }
+
+        let number = answer.parse::<i64>().ok();
+
+        for wrong in self.wrong.iter().filter(|wrong| wrong.part == part) {
+            if wrong.answer == answer {
+                return Judgement::Wrong(wrong.hint);
+            }
+
+            let bound = wrong.answer.parse::<i64>().ok();
+
+            match (number, bound, wrong.hint) {
+                (Some(number), Some(bound), Some(Hint::TooHigh)) if number >= bound => {
+                    return Judgement::Wrong(Some(Hint::TooHigh))
+                }
+                (Some(number), Some(bound), Some(Hint::TooLow)) if number <= bound => {
+                    return Judgement::Wrong(Some(Hint::TooLow))
+                }
+                _ => {}
+            }
+        }
+
+        Judgement::Unknown
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Format(PathBuf, serde_json::Error),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => {
+                write!(f, "could not access {}: {}", path.display(), err)
+            }
+            AnswersError::Format(path, err) => {
+                write!(f, "invalid answers file {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+pub fn path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("{year}/{day:02}/answers.json"))
+}
+
+impl Answers {
+    /// Loads the answers file of a day, a missing file simply means no answers are known yet
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(AnswersError::Io(path.to_path_buf(), err)),
+        };
+
+        serde_json::from_str(&content).map_err(|err| AnswersError::Format(path.to_path_buf(), err))
+    }
+
+    pub fn get(&self, input: &str, part: u8) -> Option<&str> {
+        self.inputs
+            .get(input)
+            .and_then(|answers| answers.part(part))
+            .map(String::as_str)
+    }
+
+    pub fn judge(&self, input: &str, part: u8, answer: &str) -> Judgement {
+        self.inputs
+            .get(input)
+            .map_or(Judgement::Unknown, |answers| answers.judge(part, answer))
+    }
+
+    pub fn set(&mut self, input: &str, part: u8, answer: &str) {
+        let answers = self.inputs.entry(input.to_string()).or_default();
+
+        if let Some(slot) = answers.part_mut(part) {
+            *slot = Some(answer.to_string());
+        }
+    }
+
+    pub fn add_wrong(&mut self, input: &str, part: u8, answer: &str, hint: Option<Hint>) {
+        let answers = self.inputs.entry(input.to_string()).or_default();
+
+        answers.wrong.push(WrongAnswer {
+            part,
+            answer: answer.to_string(),
+            hint,
+        });
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let mut content = serde_json::to_string_pretty(self)
+            .map_err(|err| AnswersError::Format(path.to_path_buf(), err))?;
+        content.push('\n');
+
+        std::fs::write(path, content).map_err(|err| AnswersError::Io(path.to_path_buf(), err))
+    }
+}
+
+#[cfg(test)]
+mod test_answers {
+    use super::{Answers, Hint, Judgement};
+
+    const TEST_ANSWERS: &str = r#"{
+  "input.txt": {
+    "part1": "142",
+    "part2": "281"
+  },
+  "other.txt": {
+    "part1": "12"
+  }
+}"#;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: Answers = serde_json::from_str(TEST_ANSWERS).expect("should parse");
+
+        assert_eq!(Some("142"), answers.get("input.txt", 1));
+        assert_eq!(Some("281"), answers.get("input.txt", 2));
+        assert_eq!(Some("12"), answers.get("other.txt", 1));
+        assert_eq!(None, answers.get("other.txt", 2));
+        assert_eq!(None, answers.get("missing.txt", 1));
+    }
+
+    #[test]
+    fn test_serialize_roundtrip() {
+        let answers: Answers = serde_json::from_str(TEST_ANSWERS).expect("should parse");
+
+        assert_eq!(
+            TEST_ANSWERS,
+            serde_json::to_string_pretty(&answers).expect("should serialize")
+        );
+    }
+
+    #[test]
+    fn test_judge() {
+        let mut answers = Answers::default();
+        answers.set("input.txt", 1, "142");
+        answers.add_wrong("input.txt", 2, "300", Some(Hint::TooHigh));
+        answers.add_wrong("input.txt", 2, "200", Some(Hint::TooLow));
+        answers.add_wrong("input.txt", 2, "250", None);
+
+        assert_eq!(Judgement::Correct, answers.judge("input.txt", 1, "142"));
+        assert_eq!(
+            Judgement::Mismatch("142".to_string()),
+            answers.judge("input.txt", 1, "143")
+        );
+        assert_eq!(
+            Judgement::Wrong(Some(Hint::TooHigh)),
+            answers.judge("input.txt", 2, "301")
+        );
+        assert_eq!(
+            Judgement::Wrong(Some(Hint::TooLow)),
+            answers.judge("input.txt", 2, "200")
+        );
+        assert_eq!(Judgement::Wrong(None), answers.judge("input.txt", 2, "250"));
+        assert_eq!(Judgement::Unknown, answers.judge("input.txt", 2, "281"));
+        assert_eq!(Judgement::Unknown, answers.judge("other.txt", 2, "300"));
+    }
+
+    #[test]
+    fn test_wrong_answers_roundtrip() {
+        let mut answers = Answers::default();
+        answers.add_wrong("input.txt", 2, "300", Some(Hint::TooHigh));
+
+        let json = serde_json::to_string(&answers).expect("should serialize");
+        assert_eq!(
+            r#"{"input.txt":{"wrong":[{"part":2,"answer":"300","hint":"too_high"}]}}"#,
+            json
+        );
+        assert_eq!(answers, serde_json::from_str(&json).expect("should parse"));
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+
+/// Multi-pattern matcher (Aho-Corasick) that finds every occurrence of a set of byte patterns in a
+/// single pass, overlapping ones like `eight` and `two` in `eightwo` included
+///
+/// The failure links are folded into a complete transition table at construction, so matching
+/// costs one table lookup per byte.
+pub struct Automaton<T> {
+    /// Next state for every state and byte, at `state * 256 + byte`
+    transitions: Vec<u32>,
+    /// Patterns ending in each state as their length and value, including shorter patterns that
+    /// are a suffix of the state
+    outputs: Vec<Vec<(usize, T)>>,
+}
+
+/// A pattern found at the bytes `start..end` of the haystack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<T> {
+    pub start: usize,
+    pub end: usize,
+    pub value: T,
+}
+
+const ROOT: u32 = 0;
+const MISSING: u32 = u32::MAX;
+
+impl<T: Copy> Automaton<T> {
+    /// Builds the automaton for `patterns`, a pattern given twice keeps the last value
+    pub fn new<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = (P, T)>) -> Self {
+        let mut automaton = Self {
+            transitions: vec![MISSING; 256],
+            outputs: vec![vec![]],
+        };
+
+        for (pattern, value) in patterns {
+            automaton.insert(pattern.as_ref(), value);
+        }
+
+        automaton.link();
+        automaton
+    }
+
+    /// Like `new` but ASCII letters match regardless of their case, the matches keep the byte
+    /// offsets of the haystack
+    pub fn ignoring_ascii_case<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = (P, T)>) -> Self {
+        let mut automaton = Self::new(
+            patterns
+                .into_iter()
+                .map(|(pattern, value)| (pattern.as_ref().to_ascii_lowercase(), value)),
+        );
+
+        // the trie only has lowercase edges, upper case letters move like their lower case ones
+        for state in 0..automaton.outputs.len() {
+            for byte in b'A'..=b'Z' {
+                let lower = byte.to_ascii_lowercase();
+                automaton.transitions[state * 256 + byte as usize] =
+                    automaton.transitions[state * 256 + lower as usize];
+            }
+        }
+
+        automaton
+    }
+
+    /// Adds `pattern` to the trie
+    fn insert(&mut self, pattern: &[u8], value: T) {
+        assert!(!pattern.is_empty(), "patterns should not be empty");
+
+        let mut state = ROOT;
+
+        for byte in pattern {
+            let index = state as usize * 256 + *byte as usize;
+
+            if self.transitions[index] == MISSING {
+                self.transitions[index] = self.outputs.len() as u32;
+                self.transitions.extend([MISSING; 256]);
+                self.outputs.push(vec![]);
+            }
+
+            state = self.transitions[index];
+        }
+
+        let outputs = &mut self.outputs[state as usize];
+        outputs.retain(|(length, _)| *length != pattern.len());
+        outputs.push((pattern.len(), value));
+    }
+
+    /// Computes the failure links breadth first and replaces missing transitions with the
+    /// transition of the failure state
+    fn link(&mut self) {
+        let mut failure = vec![ROOT; self.outputs.len()];
+        let mut queue = VecDeque::new();
+
+        for byte in 0..256 {
+            match self.transitions[byte] {
+                MISSING => self.transitions[byte] = ROOT,
+                child => queue.push_back(child),
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            let fallback = failure[state as usize];
+            let inherited = self.outputs[fallback as usize].clone();
+            self.outputs[state as usize].extend(inherited);
+
+            for byte in 0..256 {
+                let index = state as usize * 256 + byte;
+                let next = self.transitions[fallback as usize * 256 + byte];
+
+                match self.transitions[index] {
+                    MISSING => self.transitions[index] = next,
+                    child => {
+                        failure[child as usize] = next;
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every match in `haystack`, ordered by where they end
+    pub fn find_iter<'a>(&'a self, haystack: &'a [u8]) -> impl Iterator<Item = Match<T>> + 'a {
+        let mut state = ROOT;
+
+        haystack.iter().enumerate().flat_map(move |(index, byte)| {
+            state = self.transitions[state as usize * 256 + *byte as usize];
+
+            self.outputs[state as usize]
+                .iter()
+                .map(move |(length, value)| Match {
+                    start: index + 1 - length,
+                    end: index + 1,
+                    value: *value,
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_automaton {
+    use super::{Automaton, Match};
+
+    fn find(automaton: &Automaton<u32>, haystack: &str) -> Vec<(usize, usize, u32)> {
+        automaton
+            .find_iter(haystack.as_bytes())
+            .map(|Match { start, end, value }| (start, end, value))
+            .collect()
+    }
+
+    #[test]
+    fn test_overlapping_matches() {
+        let automaton = Automaton::new([("one", 1), ("two", 2), ("eight", 8), ("nine", 9)]);
+
+        assert_eq!(vec![(0, 5, 8), (4, 7, 2)], find(&automaton, "eightwo"));
+        assert_eq!(
+            vec![(1, 4, 2), (3, 6, 1), (5, 10, 8)],
+            find(&automaton, "xtwoneight")
+        );
+        assert_eq!(vec![(2, 6, 9)], find(&automaton, "nininex"));
+        assert!(find(&automaton, "").is_empty());
+        assert!(find(&automaton, "on tw eigh").is_empty());
+    }
+
+    #[test]
+    fn test_patterns_inside_patterns() {
+        let automaton = Automaton::new([("he", 1), ("she", 2), ("his", 3), ("hers", 4)]);
+
+        assert_eq!(
+            vec![(1, 4, 2), (2, 4, 1), (2, 6, 4)],
+            find(&automaton, "ushers")
+        );
+
+        let automaton = Automaton::new([("i", 1), ("ii", 2), ("iii", 3), ("i", 10)]);
+        assert_eq!(
+            vec![
+                (0, 1, 10),
+                (0, 2, 2),
+                (1, 2, 10),
+                (0, 3, 3),
+                (1, 3, 2),
+                (2, 3, 10)
+            ],
+            find(&automaton, "iii")
+        );
+    }
+
+    #[test]
+    fn test_ignoring_ascii_case() {
+        let automaton = Automaton::ignoring_ascii_case([("Two", 2), ("iv", 4), ("fünf", 5)]);
+
+        assert_eq!(
+            vec![(0, 3, 2), (3, 5, 4), (6, 11, 5)],
+            find(&automaton, "tWOIv fünf")
+        );
+        // only ASCII letters are folded
+        assert!(find(&automaton, "FÜNF").is_empty());
+    }
+
+    #[test]
+    fn test_multibyte_haystack() {
+        let automaton = Automaton::new([("1", 1), ("one", 1)]);
+        assert_eq!(vec![(4, 7, 1), (9, 10, 1)], find(&automaton, "ä½one½1"));
+    }
+}
@@ -0,0 +1,255 @@
+use crate::solution::Solution;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Runs that are executed before measuring to warm up caches and the branch predictor
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        };
+
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub phase: Phase,
+    pub iterations: usize,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "max_ns", with = "nanos")]
+    pub max: Duration,
+}
+
+/// Benchmark results of a single phase together with the puzzle they belong to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    /// Path of the input file, `-` for stdin
+    pub input: String,
+    /// Size of the input in bytes, together with the path it tells apart regenerated inputs
+    pub bytes: u64,
+    /// Input size divided by the median, 0 when the median is too short to measure
+    pub bytes_per_second: u64,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// Durations are stored as whole nanoseconds
+pub(crate) mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+impl Stats {
+    fn from_samples(phase: Phase, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Self {
+            phase,
+            iterations: samples.len(),
+            min: samples[0],
+            median: percentile(&samples, 50),
+            p95: percentile(&samples, 95),
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Nearest-rank percentile of already sorted samples
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (sorted.len() * percent).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+fn measure<T>(phase: Phase, options: &Options, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..options.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(phase, samples)
+}
+
+impl Record {
+    pub fn new(year: u16, day: u8, input: String, bytes: u64, stats: Stats) -> Self {
+        Self {
+            year,
+            day,
+            input,
+            bytes,
+            bytes_per_second: bytes_per_second(bytes, stats.median),
+            stats,
+        }
+    }
+
+    /// Bytes of input processed per second in decimal units, e.g. `1.52 GB/s`
+    pub fn throughput(&self) -> String {
+        throughput(self.bytes, self.stats.median)
+    }
+}
+
+fn bytes_per_second(bytes: u64, duration: Duration) -> u64 {
+    if duration.is_zero() {
+        return 0;
+    }
+
+    (bytes as f64 / duration.as_secs_f64()).round() as u64
+}
+
+fn throughput(bytes: u64, duration: Duration) -> String {
+    if duration.is_zero() {
+        return "-".to_string();
+    }
+
+    let per_second = bytes as f64 / duration.as_secs_f64();
+    let (scale, unit) = [(1e9, "GB/s"), (1e6, "MB/s"), (1e3, "KB/s")]
+        .into_iter()
+        .find(|(scale, _)| per_second >= *scale)
+        .unwrap_or((1.0, "B/s"));
+
+    format!("{:.2} {unit}", per_second / scale)
+}
+
+/// Times parsing and both parts of a solution separately
+pub fn bench<S: Solution>(input: &str, options: &Options) -> Result<Vec<Stats>, String> {
+    let parsed = S::parse(input).map_err(|err| format!("could not parse input: {err}"))?;
+
+    Ok(vec![
+        measure(Phase::Parse, options, || S::parse(black_box(input)).ok()),
+        measure(Phase::Part1, options, || S::part1(black_box(&parsed))),
+        measure(Phase::Part2, options, || S::part2(black_box(&parsed))),
+    ])
+}
+
+#[cfg(test)]
+mod test_bench {
+    use super::{bench, percentile, throughput, Options, Phase, Record, Stats};
+    use crate::solution::Solution;
+    use std::convert::Infallible;
+    use std::fmt::Display;
+    use std::time::Duration;
+
+    #[test]
+    fn test_percentile() {
+        let samples: Vec<_> = (1..=20).map(Duration::from_millis).collect();
+
+        assert_eq!(Duration::from_millis(10), percentile(&samples, 50));
+        assert_eq!(Duration::from_millis(19), percentile(&samples, 95));
+        assert_eq!(Duration::from_millis(20), percentile(&samples, 100));
+        assert_eq!(Duration::from_millis(1), percentile(&samples[..1], 95));
+    }
+
+    #[test]
+    fn test_throughput() {
+        assert_eq!(
+            "1.50 GB/s",
+            throughput(3_000_000_000, Duration::from_secs(2))
+        );
+        assert_eq!(
+            "250.00 MB/s",
+            throughput(1_000_000, Duration::from_millis(4))
+        );
+        assert_eq!("1.00 KB/s", throughput(1000, Duration::from_secs(1)));
+        assert_eq!("0.50 B/s", throughput(1, Duration::from_secs(2)));
+        assert_eq!("-", throughput(1, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_record_json() {
+        let median = Duration::from_millis(4);
+        let stats = Stats {
+            phase: Phase::Part1,
+            iterations: 1,
+            min: median,
+            median,
+            p95: median,
+            max: median,
+        };
+        let record = Record::new(2023, 1, "2023/01/input.txt".to_string(), 1_000_000, stats);
+        let json = serde_json::to_string(&record).expect("should serialize");
+
+        assert_eq!("250.00 MB/s", record.throughput());
+        assert!(json.contains(r#""bytes":1000000"#));
+        assert!(json.contains(r#""bytes_per_second":250000000"#));
+        assert_eq!(record, serde_json::from_str(&json).expect("should parse"));
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input<'a> = usize;
+        type Error = Infallible;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Display {
+            *input
+        }
+
+        fn part2(input: &Self::Input<'_>) -> impl Display {
+            input * 2
+        }
+    }
+
+    #[test]
+    fn test_bench_phases() {
+        let options = Options {
+            warmup: 1,
+            iterations: 5,
+        };
+        let stats = bench::<Lines>("a\nb", &options).expect("should parse");
+
+        let phases: Vec<_> = stats.iter().map(|stats| stats.phase).collect();
+        assert_eq!(vec![Phase::Parse, Phase::Part1, Phase::Part2], phases);
+
+        for stats in stats.iter() {
+            assert_eq!(5, stats.iterations);
+            assert!(stats.min <= stats.median);
+            assert!(stats.median <= stats.p95);
+            assert!(stats.p95 <= stats.max);
+        }
+    }
+}
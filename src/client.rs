@@ -0,0 +1,314 @@
+use std::fmt::{self, Display};
+use std::io::Read;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the Advent of Code servers as requested by the event's automation rules
+pub const USER_AGENT: &str = concat!(
+    "github.com/atomicptr/advent-of-code v",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Minimum time between two requests made by the same client
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(3);
+
+const SESSION_ENV: &str = "AOC_SESSION";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    /// The server refused the session cookie, usually because it expired
+    Unauthorized,
+    /// The puzzle does not exist or has not been unlocked yet
+    NotFound,
+    Status(u16, String),
+    Transport(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => {
+                let path = session_path().unwrap_or(PathBuf::from("~/.config/aoc/session"));
+                write!(
+                    f,
+                    "no session token found, set {} or write it to {}",
+                    SESSION_ENV,
+                    path.display()
+                )
+            }
+            ClientError::Unauthorized => write!(f, "session token was rejected, log in again"),
+            ClientError::NotFound => write!(f, "puzzle not found or not unlocked yet"),
+            ClientError::Status(status, body) => {
+                write!(f, "unexpected response ({status}): {}", body.trim())
+            }
+            ClientError::Transport(err) => write!(f, "request failed: {err}"),
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, falling back to `~/.config/aoc/session`
+pub fn session_path() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config| config.join("aoc").join("session"))
+}
+
+/// Reads the session cookie from the `AOC_SESSION` environment variable or the config file
+pub fn session() -> Result<String, ClientError> {
+    let session = std::env::var(SESSION_ENV)
+        .ok()
+        .or_else(|| session_path().and_then(|path| std::fs::read_to_string(path).ok()))
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty());
+
+    session.ok_or(ClientError::MissingSession)
+}
+
+struct Throttle {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl Throttle {
+    fn wait(&mut self) {
+        if let Some(elapsed) = self.last.map(|last| last.elapsed()) {
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+
+        self.last = Some(Instant::now());
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    throttle: Throttle,
+}
+
+impl Client {
+    /// Creates a client that waits at least `throttle` between two requests
+    pub fn new(base_url: &str, session: &str, throttle: Duration) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent,
+            throttle: Throttle {
+                interval: throttle,
+                last: None,
+            },
+        }
+    }
+
+    pub fn input(&mut self, year: u16, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.send(self.agent.get(&url), None)
+    }
+
+    /// Posts an answer and returns the HTML page the server responds with
+    pub fn answer(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+
+        self.send(
+            self.agent.post(&url),
+            Some(&[("level", &level), ("answer", answer)]),
+        )
+    }
+
+    fn send(
+        &mut self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, ClientError> {
+        self.throttle.wait();
+
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+
+        match response {
+            Ok(response) => read_body(response),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = read_body(response).unwrap_or_default();
+
+                Err(match status {
+                    // the server answers 400 when the cookie is missing or invalid
+                    400 | 401 | 403 => ClientError::Unauthorized,
+                    404 => ClientError::NotFound,
+                    status => ClientError::Status(status, body),
+                })
+            }
+            Err(err) => Err(ClientError::Transport(err.to_string())),
+        }
+    }
+}
+
+fn read_body(response: ureq::Response) -> Result<String, ClientError> {
+    let mut body = String::new();
+
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|err| ClientError::Transport(err.to_string()))?;
+
+    Ok(body)
+}
+
+/// Minimal HTTP server standing in for adventofcode.com, answering each connection with the
+/// next canned response and handing back the raw requests it received
+#[cfg(test)]
+pub mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("should bind");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().expect("should accept");
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).expect("should read");
+
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+
+                    request.push_str(&line);
+
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+
+                let mut payload = vec![0; content_length];
+                reader.read_exact(&mut payload).expect("should read body");
+                request.push_str(&String::from_utf8_lossy(&payload));
+                requests.push(request);
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .expect("should write");
+            }
+
+            requests
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod test_client {
+    use super::test_server::serve;
+    use super::{Client, ClientError, USER_AGENT};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_download_input() {
+        let (base_url, server) = serve(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+        let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+
+        let input = client.input(2023, 1).expect("should download");
+        assert_eq!("1abc2\npqr3stu8vwx\n", input);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=secret"));
+        assert!(requests[0].contains(&format!("User-Agent: {USER_AGENT}")));
+    }
+
+    #[test]
+    fn test_post_answer() {
+        let (base_url, server) = serve(vec![(
+            200,
+            "<article><p>That's the right answer!</p></article>",
+        )]);
+        let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+
+        let body = client.answer(2023, 4, 2, "30").expect("should post");
+        assert!(body.contains("right answer"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/4/answer HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=secret"));
+        assert!(requests[0].ends_with("level=2&answer=30"));
+    }
+
+    #[test]
+    fn test_error_responses() {
+        let (base_url, server) = serve(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (500, "Internal Server Error"),
+        ]);
+        let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+
+        assert!(matches!(
+            client.input(2023, 1),
+            Err(ClientError::Unauthorized)
+        ));
+        assert!(matches!(client.input(2023, 25), Err(ClientError::NotFound)));
+        assert!(matches!(
+            client.input(2023, 2),
+            Err(ClientError::Status(500, _))
+        ));
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_throttle() {
+        let (base_url, server) = serve(vec![(200, "a"), (200, "b")]);
+        let mut client = Client::new(&base_url, "secret", Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.input(2023, 1).expect("should download");
+        client.input(2023, 2).expect("should download");
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+}
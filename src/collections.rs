@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::AddAssign;
+
+/// Adds the counts of `other` onto `counts`, keys missing in `counts` start at zero
+pub fn merge_counts<K, V>(counts: &mut HashMap<K, V>, other: &HashMap<K, V>)
+where
+    K: Hash + Eq + Copy,
+    V: AddAssign + Default + Copy,
+{
+    for (key, value) in other.iter() {
+        *counts.entry(*key).or_default() += *value;
+    }
+}
+
+#[cfg(test)]
+mod test_collections {
+    use super::merge_counts;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_merge_counts() {
+        let mut counts = HashMap::from([(1, 1), (2, 2)]);
+        merge_counts(&mut counts, &HashMap::from([(2, 3), (3, 4)]));
+
+        assert_eq!(HashMap::from([(1, 1), (2, 5), (3, 4)]), counts);
+    }
+}
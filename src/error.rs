@@ -0,0 +1,135 @@
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+/// Error of a puzzle parser pointing at the offending part of the input
+///
+/// ```text
+/// line 3, column 9: expected a cube count, found `x`
+///   |
+/// 3 | Game 3: x blue
+///   |         ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the input
+    pub line: usize,
+    pub expected: String,
+    pub found: String,
+    source: String,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    /// Creates an error for the bytes `span` of `source`, which is line `line` of the input
+    pub fn new(line: usize, source: &str, span: Range<usize>, expected: impl Into<String>) -> Self {
+        let span = span.start.min(source.len())..span.end.min(source.len());
+        let snippet = &source[span.clone()];
+
+        let found = if !snippet.is_empty() {
+            format!("`{snippet}`")
+        } else if span.start == source.len() {
+            "end of line".to_string()
+        } else {
+            "nothing".to_string()
+        };
+
+        Self {
+            line,
+            expected: expected.into(),
+            found,
+            source: source.to_string(),
+            span,
+        }
+    }
+
+    /// 1-based byte column within the line
+    pub fn column(&self) -> usize {
+        self.span.start + 1
+    }
+
+    /// The offending part of the line
+    pub fn snippet(&self) -> &str {
+        &self.source[self.span.clone()]
+    }
+
+    /// Replaces the description of what was found, by default it is the offending snippet
+    pub fn with_found(mut self, found: impl Into<String>) -> Self {
+        self.found = found.into();
+        self
+    }
+
+    /// Moves the error to another line, for parsers of a single line that do not know where it
+    /// is in the whole input
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line,
+            self.column(),
+            self.expected,
+            self.found
+        )?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        let offset = self.source[..self.span.start].chars().count();
+        let carets = self.snippet().chars().count().max(1);
+
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source)?;
+        write!(f, "{gutter} | {}{}", " ".repeat(offset), "^".repeat(carets))
+    }
+}
+
+#[cfg(test)]
+mod test_error {
+    use super::ParseError;
+
+    #[test]
+    fn test_render() {
+        let line = "Game 3: x blue";
+        let err = ParseError::new(3, line, 8..9, "a cube count");
+
+        assert_eq!(9, err.column());
+        assert_eq!("x", err.snippet());
+        assert_eq!(
+            "\
+line 3, column 9: expected a cube count, found `x`
+  |
+3 | Game 3: x blue
+  |         ^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_render_end_of_line() {
+        let err = ParseError::new(12, "Card 1", 6..6, "`:`");
+
+        assert_eq!(
+            "\
+line 12, column 7: expected `:`, found end of line
+   |
+12 | Card 1
+   |       ^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_on_line_and_found() {
+        let err = ParseError::new(1, "abc", 0..3, "`def`")
+            .on_line(7)
+            .with_found("3 columns");
+
+        assert_eq!(7, err.line);
+        assert_eq!("3 columns", err.found);
+        assert_eq!("abc", err.snippet());
+    }
+}
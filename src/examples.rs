@@ -0,0 +1,177 @@
+use crate::registry::Puzzle;
+use crate::verify::Check;
+use std::path::{Path, PathBuf};
+
+/// Info string of the fenced code blocks in a day's README that hold an example
+const FENCE: &str = "```example";
+
+/// An example input from a day's README together with the answers the puzzle text gives for it
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    /// Line of the README the example block starts on
+    pub line: usize,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+pub fn path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(format!("{year}/{day:02}/README.md"))
+}
+
+/// Parses all example blocks of a README, they look like
+///
+/// ````markdown
+/// ```example part1=142 part2=281
+/// 1abc2
+/// ```
+/// ````
+pub fn parse(readme: &str) -> Result<Vec<Example>, String> {
+    let mut examples = vec![];
+    let mut lines = readme.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let Some(info) = line.trim_end().strip_prefix(FENCE) else {
+            continue;
+        };
+
+        let mut example = Example {
+            line: index + 1,
+            input: String::new(),
+            part1: None,
+            part2: None,
+        };
+
+        for pair in info.split_whitespace() {
+            let (key, value) = pair.split_once('=').ok_or(format!(
+                "line {}: expected key=value, found {pair}",
+                index + 1
+            ))?;
+
+            let value = Some(value.to_string()).filter(|value| !value.is_empty());
+
+            match key {
+                "part1" => example.part1 = value,
+                "part2" => example.part2 = value,
+                key => return Err(format!("line {}: unknown key {key}", index + 1)),
+            }
+        }
+
+        let mut terminated = false;
+
+        for (_, line) in lines.by_ref() {
+            if line.trim_end() == "```" {
+                terminated = true;
+                break;
+            }
+
+            example.input.push_str(line);
+            example.input.push('\n');
+        }
+
+        if !terminated {
+            return Err(format!("line {}: example is never closed", index + 1));
+        }
+
+        examples.push(example);
+    }
+
+    Ok(examples)
+}
+
+/// Solves every part of an example that has an expected answer
+pub fn check(puzzle: &Puzzle, example: &Example) -> Vec<Check> {
+    [(1, &example.part1), (2, &example.part2)]
+        .into_iter()
+        .filter_map(|(part, expected)| {
+            let expected = expected.clone()?;
+
+            Some(Check {
+                part,
+                answer: puzzle.solve(&example.input, part),
+                expected: Some(expected),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_examples {
+    use super::{check, parse, path, Example};
+    use crate::registry::PUZZLES;
+    use crate::verify::Status;
+    use std::path::Path;
+
+    const TEST_README: &str = "\
+# 2023-01
+
+```example part1=142
+1abc2
+treb7uchet
+```
+
+```rust
+fn main() {}
+```
+
+```example part2=281
+two1nine
+```
+";
+
+    #[test]
+    fn test_parse_readme() {
+        let examples = parse(TEST_README).expect("should parse");
+
+        assert_eq!(
+            vec![
+                Example {
+                    line: 3,
+                    input: "1abc2\ntreb7uchet\n".to_string(),
+                    part1: Some("142".to_string()),
+                    part2: None,
+                },
+                Example {
+                    line: 12,
+                    input: "two1nine\n".to_string(),
+                    part1: None,
+                    part2: Some("281".to_string()),
+                },
+            ],
+            examples
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_readme() {
+        assert!(parse("```example part3=1\n```").is_err());
+        assert!(parse("```example 142\n```").is_err());
+        assert!(parse("```example part1=1\n1abc2\n").is_err());
+    }
+
+    #[test]
+    fn test_readme_examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        for puzzle in PUZZLES {
+            let path = path(root, puzzle.year, puzzle.day);
+            let readme = std::fs::read_to_string(&path).expect("README should exist");
+            let examples = parse(&readme).expect("README should parse");
+
+            for example in examples.iter() {
+                for check in check(puzzle, example) {
+                    assert_eq!(
+                        Status::Pass,
+                        check.status(),
+                        "{}:{} part {}: expected {:?} but got {:?}",
+                        path.display(),
+                        example.line,
+                        check.part,
+                        check.expected,
+                        check.answer
+                    );
+                }
+            }
+        }
+    }
+}
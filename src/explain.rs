@@ -0,0 +1,64 @@
+use std::io::{self, IsTerminal, Write};
+
+/// Writes how a day arrived at the answer of a part, e.g. which tokens of each line counted
+pub type Explainer = fn(&str, u8, Style, &mut dyn Write) -> io::Result<()>;
+
+/// Whether explanations highlight text with ANSI colours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    Ansi,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Green,
+    Blue,
+    Cyan,
+}
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Green => "32",
+            Color::Blue => "34",
+            Color::Cyan => "36",
+        }
+    }
+}
+
+impl Style {
+    /// Colours when stdout is a terminal, unless `NO_COLOR` is set
+    pub fn detect() -> Self {
+        let disabled = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+
+        if io::stdout().is_terminal() && !disabled {
+            Style::Ansi
+        } else {
+            Style::Plain
+        }
+    }
+
+    /// `text` in bold `color`, plain text stays unchanged
+    pub fn paint(self, text: &str, color: Color) -> String {
+        match self {
+            Style::Plain => text.to_string(),
+            Style::Ansi => format!("\x1b[1;{}m{text}\x1b[0m", color.code()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_explain {
+    use super::{Color, Style};
+
+    #[test]
+    fn test_paint() {
+        assert_eq!("two", Style::Plain.paint("two", Color::Green));
+        assert_eq!(
+            "\x1b[1;34mtwo\x1b[0m",
+            Style::Ansi.paint("two", Color::Blue)
+        );
+        assert_eq!("\x1b[1;36m\x1b[0m", Style::Ansi.paint("", Color::Cyan));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::client::{Client, ClientError};
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input already existed and was not downloaded again
+    Cached,
+    Downloaded,
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Client(ClientError),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Client(err) => err.fmt(f),
+            FetchError::Io(path, err) => write!(f, "could not write {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl From<ClientError> for FetchError {
+    fn from(err: ClientError) -> Self {
+        FetchError::Client(err)
+    }
+}
+
+/// Downloads the input of a day to `path` unless it already exists there
+pub fn fetch(
+    client: &mut Client,
+    year: u16,
+    day: u8,
+    path: &Path,
+    force: bool,
+) -> Result<Fetched, FetchError> {
+    let cached = std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0);
+
+    if cached && !force {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.input(year, day)?;
+    let io_err = |err| FetchError::Io(path.to_path_buf(), err);
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_err)?;
+    }
+
+    std::fs::write(path, input).map_err(io_err)?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod test_fetch {
+    use super::{fetch, Fetched};
+    use crate::client::test_server::serve;
+    use crate::client::Client;
+    use std::time::Duration;
+
+    #[test]
+    fn test_fetch_is_cached() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("2023/01/input.txt");
+
+        let (base_url, server) = serve(vec![(200, "first\n"), (200, "second\n")]);
+        let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+
+        assert_eq!(
+            Fetched::Downloaded,
+            fetch(&mut client, 2023, 1, &path, false).unwrap()
+        );
+        assert_eq!(
+            Fetched::Cached,
+            fetch(&mut client, 2023, 1, &path, false).unwrap()
+        );
+        assert_eq!("first\n", std::fs::read_to_string(&path).unwrap());
+
+        assert_eq!(
+            Fetched::Downloaded,
+            fetch(&mut client, 2023, 1, &path, true).unwrap()
+        );
+        assert_eq!("second\n", std::fs::read_to_string(&path).unwrap());
+
+        assert_eq!(2, server.join().unwrap().len());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+use std::io::{self, Write};
+
+/// Writes a random puzzle input in the format the day's parser accepts
+pub type Generator = fn(&mut Rng, &Options, &mut dyn Write) -> io::Result<()>;
+
+/// What a generated input should look like, days pick their own defaults for unset options
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Options {
+    /// Number of lines, games or cards, or the side length of a grid
+    pub size: Option<usize>,
+    /// Between 0 and 1, what it controls depends on the day, e.g. the share of gears in a
+    /// schematic or how often a scratch card number wins
+    pub density: Option<f64>,
+}
+
+/// Small deterministic random number generator (SplitMix64), the same seed always generates the
+/// same input on every platform
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number in `0..bound`, `bound` has to be positive
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "bound should be positive");
+
+        // the modulo bias is negligible for the small bounds inputs are generated with
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Uniform number in `low..=high`
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        low + self.below(high - low + 1)
+    }
+
+    /// True with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // the upper 53 bits give a uniform float in 0..1
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_generate {
+    use super::{Options, Rng};
+    use crate::registry::PUZZLES;
+
+    #[test]
+    fn test_deterministic() {
+        let first: Vec<u64> = (0..5).map(|_| Rng::new(7).next_u64()).collect();
+        assert!(first.iter().all(|value| *value == first[0]));
+
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(8);
+        assert_ne!(a.next_u64(), b.next_u64());
+
+        // reference values of SplitMix64 seeded with 0
+        let mut rng = Rng::new(0);
+        assert_eq!(0xe220a8397b1dcdaf, rng.next_u64());
+        assert_eq!(0x6e789e6aa1b965f4, rng.next_u64());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(1);
+
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((5..=9).contains(&rng.between(5, 9)));
+        }
+
+        assert!(!(0..1000).any(|_| rng.chance(0.0)));
+        assert!((0..1000).all(|_| rng.chance(1.0)));
+
+        let hits = (0..10_000).filter(|_| rng.chance(0.25)).count();
+        assert!((2000..3000).contains(&hits), "{hits}");
+
+        let mut items: Vec<usize> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!((0..20).collect::<Vec<_>>(), items);
+        items.sort();
+        assert_eq!((0..20).collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for puzzle in PUZZLES {
+            let Some(generator) = puzzle.generator() else {
+                continue;
+            };
+
+            let cases = [
+                (1, Some(1), Some(0.0)),
+                (2, Some(5), Some(1.0)),
+                (3, Some(40), Some(0.3)),
+                (4, Some(100), Some(0.1)),
+                (5, Some(300), Some(1.0)),
+                (6, None, None),
+            ];
+
+            for (seed, size, density) in cases {
+                let options = Options { size, density };
+
+                let mut input = vec![];
+                generator(&mut Rng::new(seed), &options, &mut input).unwrap();
+                let input = String::from_utf8(input).unwrap();
+
+                let mut again = vec![];
+                generator(&mut Rng::new(seed), &options, &mut again).unwrap();
+                assert_eq!(input.as_bytes(), again, "{puzzle} should be deterministic");
+
+                for part in [1, 2] {
+                    let answer = puzzle.solve(&input, part);
+                    assert!(answer.is_ok(), "{puzzle} seed {seed}: {answer:?}\n{input}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// Position of a cell in a [`Grid`], rows grow downwards and columns to the right
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Point {
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// Moves the point by the given offset, `None` if it would leave the non-negative quadrant
+    fn offset(self, (row, column): (isize, isize)) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(row)?,
+            column: self.column.checked_add_signed(column)?,
+        })
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, column): (usize, usize)) -> Self {
+        Self { row, column }
+    }
+}
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    /// A row has a different length than the first row, `line` is 1-based
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid is empty"),
+            GridError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} columns but the grid is {expected} wide"
+            ),
+        }
+    }
+}
+
+/// Rectangular grid stored row by row in a single `Vec`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one row per non-empty line, mapping every char to a cell
+    pub fn parse(s: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (index, line) in s.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let found = cells.len() - before;
+
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::RaggedRow {
+                        line: index + 1,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            None => Err(GridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.column < self.width
+    }
+
+    pub fn get(&self, point: impl Into<Point>) -> Option<&T> {
+        let point = point.into();
+
+        self.contains(point)
+            .then(|| &self.cells[point.row * self.width + point.column])
+    }
+
+    pub fn get_mut(&mut self, point: impl Into<Point>) -> Option<&mut T> {
+        let point = point.into();
+
+        if !self.contains(point) {
+            return None;
+        }
+
+        Some(&mut self.cells[point.row * self.width + point.column])
+    }
+
+    /// Every position of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| Point::new(row, column)))
+    }
+
+    /// Every cell together with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        point: Point,
+        offsets: [(isize, isize); N],
+    ) -> impl Iterator<Item = Point> + '_ {
+        offsets
+            .into_iter()
+            .filter_map(move |offset| point.offset(offset))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The up to 4 orthogonally adjacent positions
+    pub fn neighbours4(&self, point: impl Into<Point>) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point.into(), OFFSETS_4)
+    }
+
+    /// The up to 8 surrounding positions including the diagonals
+    pub fn neighbours8(&self, point: impl Into<Point>) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point.into(), OFFSETS_8)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero width, an empty grid simply has no rows
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        // skipping past the width would walk a column of the next row instead
+        assert!(
+            column < self.width,
+            "column {column} is outside of the grid"
+        );
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// Cells from `start` going down and to the right until the edge of the grid
+    pub fn diagonal(&self, start: impl Into<Point>) -> impl Iterator<Item = &T> {
+        self.ray(start.into(), (1, 1))
+    }
+
+    /// Cells from `start` going down and to the left until the edge of the grid
+    pub fn anti_diagonal(&self, start: impl Into<Point>) -> impl Iterator<Item = &T> {
+        self.ray(start.into(), (1, -1))
+    }
+
+    fn ray(&self, start: Point, step: (isize, isize)) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(start), move |point| point.offset(step))
+            .map_while(|point| self.get(point))
+    }
+
+    /// Renders the grid back to text with one line per row
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows() {
+            text.extend(row.iter().map(&mut cell));
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+impl<T, P: Into<Point>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: P) -> &Self::Output {
+        let point = point.into();
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T, P: Into<Point>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, point: P) -> &mut Self::Output {
+        let point = point.into();
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod test_grid {
+    use super::{Grid, GridError, Point};
+
+    const TEST_GRID: &str = "\
+abc
+def
+ghi
+jkl";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(TEST_GRID, |c| c).expect("should parse")
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = grid();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(4, grid.height());
+        assert_eq!('f', grid[(1, 2)]);
+        assert_eq!('j', grid[Point::new(3, 0)]);
+        assert_eq!(None, grid.get((4, 0)));
+        assert_eq!(None, grid.get((0, 3)));
+
+        assert_eq!(format!("{TEST_GRID}\n"), grid.render(|c| *c));
+        assert_eq!(
+            "ABC\nDEF\nGHI\nJKL\n",
+            grid.render(|c| c.to_ascii_uppercase())
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(Err(GridError::Empty), Grid::parse("\n\n", |c| c));
+        assert_eq!(
+            Err(GridError::RaggedRow {
+                line: 3,
+                expected: 3,
+                found: 2
+            }),
+            Grid::parse("abc\ndef\ngh\n", |c| c)
+        );
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 5;
+        *grid.get_mut(Point::new(0, 1)).unwrap() += 2;
+
+        assert_eq!("02\n50\n", grid.render(|value| (b'0' + *value) as char));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        let center: Vec<_> = grid.neighbours8((1, 1)).map(|p| grid[p]).collect();
+        assert_eq!(vec!['a', 'b', 'c', 'd', 'f', 'g', 'h', 'i'], center);
+
+        let corner: Vec<_> = grid.neighbours8((0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(vec!['b', 'd', 'e'], corner);
+
+        let edge: Vec<_> = grid.neighbours4((3, 2)).map(|p| grid[p]).collect();
+        assert_eq!(vec!['i', 'k'], edge);
+
+        let center: Vec<_> = grid.neighbours4((1, 1)).map(|p| grid[p]).collect();
+        assert_eq!(vec!['b', 'd', 'f', 'h'], center);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!(4, grid.rows().count());
+        assert_eq!("behk", grid.column(1).collect::<String>());
+        assert_eq!("aei", grid.diagonal((0, 0)).collect::<String>());
+        assert_eq!("dhl", grid.diagonal((1, 0)).collect::<String>());
+        assert_eq!("ceg", grid.anti_diagonal((0, 2)).collect::<String>());
+        assert_eq!("fhj", grid.anti_diagonal((1, 2)).collect::<String>());
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of the grid")]
+    fn test_column_outside() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid = grid();
+        let (point, value) = grid.iter().nth(4).unwrap();
+
+        assert_eq!(Point::new(1, 1), point);
+        assert_eq!('e', *value);
+        assert_eq!(12, grid.points().count());
+    }
+}
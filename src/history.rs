@@ -0,0 +1,284 @@
+use crate::bench::{Phase, Record};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_PATH: &str = ".bench-history.jsonl";
+
+/// A single benchmark run, stored as one JSON line in the history file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub commit: String,
+    pub timestamp: u64,
+    pub records: Vec<Record>,
+}
+
+impl Entry {
+    pub fn new(records: Vec<Record>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        Self {
+            commit: current_commit(),
+            timestamp,
+            records,
+        }
+    }
+
+    /// The record of the same phase of the same puzzle measured on the same input as `other`
+    fn find(&self, other: &Record) -> Option<&Record> {
+        self.records.iter().find(|record| {
+            (record.year, record.day, record.stats.phase)
+                == (other.year, other.day, other.stats.phase)
+                && (&record.input, record.bytes) == (&other.input, other.bytes)
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(PathBuf, io::Error),
+    Format(PathBuf, usize, serde_json::Error),
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Io(path, err) => {
+                write!(f, "could not access {}: {}", path.display(), err)
+            }
+            HistoryError::Format(path, line, err) => {
+                write!(f, "invalid entry in {}:{}: {}", path.display(), line, err)
+            }
+        }
+    }
+}
+
+/// Short hash of the checked out commit, suffixed with `-dirty` when there are uncommitted changes
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{commit}-dirty"),
+            _ => commit,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+pub fn load(path: &Path) -> Result<Vec<Entry>, HistoryError> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(HistoryError::Io(path.to_path_buf(), err)),
+    };
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map_err(|err| HistoryError::Format(path.to_path_buf(), index + 1, err))
+        })
+        .collect()
+}
+
+pub fn append(path: &Path, entry: &Entry) -> Result<(), HistoryError> {
+    let line = serde_json::to_string(entry).expect("entry should serialize");
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{line}"))
+        .map_err(|err| HistoryError::Io(path.to_path_buf(), err))
+}
+
+/// Picks the run to compare the latest run against: the most recent run of `commit` if given,
+/// otherwise the most recent run of a different commit than the latest one
+///
+/// Only runs that measured at least one puzzle on the same input as the latest run count, timings
+/// of a large generated input say nothing about the real one.
+pub fn baseline<'a>(entries: &'a [Entry], commit: Option<&str>) -> Option<&'a Entry> {
+    let (current, previous) = entries.split_last()?;
+
+    previous.iter().rev().find(|entry| {
+        let same_input = current
+            .records
+            .iter()
+            .any(|record| entry.find(record).is_some());
+
+        same_input
+            && match commit {
+                Some(commit) => entry.commit.starts_with(commit),
+                None => entry.commit != current.commit,
+            }
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change of the median in percent, positive means slower
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+
+        if baseline == 0.0 {
+            return 0.0;
+        }
+
+        (self.current.as_secs_f64() - baseline) / baseline * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares the medians of every phase that was measured in both runs
+pub fn compare(baseline: &Entry, current: &Entry) -> Vec<Comparison> {
+    current
+        .records
+        .iter()
+        .filter_map(|record| {
+            let previous = baseline.find(record)?;
+
+            Some(Comparison {
+                year: record.year,
+                day: record.day,
+                phase: record.stats.phase,
+                baseline: previous.stats.median,
+                current: record.stats.median,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_history {
+    use super::{baseline, compare, Entry};
+    use crate::bench::{Phase, Record, Stats};
+    use std::time::Duration;
+
+    fn record(day: u8, phase: Phase, median_ms: u64) -> Record {
+        record_on(&format!("2023/{day:02}/input.txt"), day, phase, median_ms)
+    }
+
+    fn record_on(input: &str, day: u8, phase: Phase, median_ms: u64) -> Record {
+        let median = Duration::from_millis(median_ms);
+
+        let stats = Stats {
+            phase,
+            iterations: 10,
+            min: median,
+            median,
+            p95: median,
+            max: median,
+        };
+
+        Record::new(2023, day, input.to_string(), 20_000, stats)
+    }
+
+    fn entry(commit: &str, records: Vec<Record>) -> Entry {
+        Entry {
+            commit: commit.to_string(),
+            timestamp: 0,
+            records,
+        }
+    }
+
+    #[test]
+    fn test_entry_roundtrip() {
+        let entry = entry("abc1234", vec![record(3, Phase::Part2, 12)]);
+        let line = serde_json::to_string(&entry).expect("should serialize");
+
+        assert!(line.contains(r#""median_ns":12000000"#));
+        assert_eq!(entry, serde_json::from_str(&line).expect("should parse"));
+    }
+
+    #[test]
+    fn test_baseline() {
+        let entries = vec![
+            entry("aaa", vec![record(1, Phase::Part1, 10)]),
+            entry("bbb", vec![record(1, Phase::Part1, 10)]),
+            entry("ccc", vec![record(1, Phase::Part1, 10)]),
+            entry("ccc", vec![record(1, Phase::Part1, 10)]),
+        ];
+
+        assert_eq!("bbb", baseline(&entries, None).unwrap().commit);
+        assert_eq!("aaa", baseline(&entries, Some("aa")).unwrap().commit);
+        assert_eq!("ccc", baseline(&entries, Some("ccc")).unwrap().commit);
+        assert!(baseline(&entries[..1], None).is_none());
+        assert!(baseline(&entries[2..], None).is_none());
+    }
+
+    #[test]
+    fn test_baseline_same_input() {
+        let real = record(1, Phase::Part1, 10);
+        let large = record_on("large.txt", 1, Phase::Part1, 900);
+        let regenerated = Record {
+            bytes: 40_000,
+            ..large.clone()
+        };
+
+        let entries = vec![
+            entry("aaa", vec![real.clone()]),
+            entry("bbb", vec![large.clone()]),
+            entry("ccc", vec![real.clone()]),
+        ];
+        assert_eq!("aaa", baseline(&entries, None).unwrap().commit);
+
+        let entries = vec![
+            entry("aaa", vec![large]),
+            entry("bbb", vec![real]),
+            entry("ccc", vec![regenerated]),
+        ];
+        assert!(baseline(&entries, None).is_none());
+        assert!(compare(&entries[0], &entries[2]).is_empty());
+    }
+
+    #[test]
+    fn test_compare() {
+        let previous = entry(
+            "aaa",
+            vec![record(1, Phase::Part1, 10), record(1, Phase::Part2, 10)],
+        );
+        let current = entry(
+            "bbb",
+            vec![
+                record(1, Phase::Part1, 11),
+                record(1, Phase::Part2, 13),
+                record(2, Phase::Part1, 100),
+            ],
+        );
+
+        let comparisons = compare(&previous, &current);
+        assert_eq!(2, comparisons.len());
+
+        assert!((comparisons[0].change() - 10.0).abs() < 1e-9);
+        assert!(!comparisons[0].is_regression(10.0));
+        assert!(comparisons[1].is_regression(10.0));
+        assert!(!comparisons[1].is_regression(50.0));
+    }
+}
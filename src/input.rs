@@ -0,0 +1,112 @@
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle input for a run should be read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `<year>/<day>/input.txt` relative to the current directory
+    Default,
+    Stdin,
+    File(PathBuf),
+}
+
+impl From<&str> for InputSource {
+    fn from(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => {
+                write!(f, "input file {} does not exist", path.display())
+            }
+            InputError::Io(path, err) => write!(f, "could not read {}: {}", path.display(), err),
+            InputError::Stdin(err) => write!(f, "could not read input from stdin: {}", err),
+        }
+    }
+}
+
+impl InputSource {
+    /// Path the input is read from, `-` for stdin
+    pub fn name(&self, year: u16, day: u8) -> String {
+        match self {
+            InputSource::Default => default_path(year, day).display().to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "-".to_string(),
+        }
+    }
+}
+
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("{year}/{day:02}/input.txt"))
+}
+
+pub fn load(year: u16, day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Default => read_file(&default_path(year, day)),
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+            Ok(input)
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::Io(path.to_path_buf(), err),
+    })
+}
+
+#[cfg(test)]
+mod test_input {
+    use super::{default_path, load, InputError, InputSource};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_input_source_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("example.txt")),
+            InputSource::from("example.txt")
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!("2023/04/input.txt", InputSource::Default.name(2023, 4));
+        assert_eq!("-", InputSource::Stdin.name(2023, 4));
+        assert_eq!("big.txt", InputSource::from("big.txt").name(2023, 4));
+    }
+
+    #[test]
+    fn test_default_path() {
+        assert_eq!(PathBuf::from("2023/03/input.txt"), default_path(2023, 3));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        let err = load(2023, 1, &source).expect_err("should not exist");
+
+        assert!(matches!(err, InputError::NotFound(_)));
+        assert!(err.to_string().contains("does/not/exist.txt"));
+    }
+}
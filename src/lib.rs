@@ -0,0 +1,24 @@
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod client;
+pub mod collections;
+pub mod error;
+pub mod examples;
+pub mod explain;
+pub mod fetch;
+pub mod generate;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod parse;
+pub mod registry;
+pub mod scaffold;
+pub mod solution;
+pub mod stream;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+#[path = "../2023/mod.rs"]
+pub mod y2023;
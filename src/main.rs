@@ -0,0 +1,818 @@
+use advent_of_code::explain::Style;
+use advent_of_code::input::{self, InputSource};
+use advent_of_code::{
+    answers, bench, client, examples, fetch, generate, history, registry, scaffold, solution,
+    submit, verify, watch,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode, Stdio};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day or every day of a year
+    Run {
+        year: u16,
+        day: Option<u8>,
+        /// Only run this part (1 or 2)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from this file, or from stdin when set to `-`
+        #[arg(short, long, requires = "day")]
+        input: Option<String>,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Show how the answer comes about line by line, for days that support it
+        #[arg(long, conflicts_with = "format")]
+        explain: bool,
+    },
+    /// List all registered puzzles
+    List,
+    /// Check the answers of every registered day against the known answers
+    Verify { year: Option<u16>, day: Option<u8> },
+    /// Check the examples in the README of every registered day
+    Examples { year: Option<u16>, day: Option<u8> },
+    /// Time parsing and both parts of a single day or every registered day
+    Bench {
+        year: Option<u16>,
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// Unmeasured runs before the measured iterations
+        #[arg(long, default_value_t = 10)]
+        warmup: usize,
+        /// Measured runs per phase
+        #[arg(short = 'n', long, default_value_t = 100)]
+        iterations: usize,
+        /// Read the puzzle input from this file, or from stdin when set to `-`
+        #[arg(short, long, requires = "day")]
+        input: Option<String>,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// File the results of this run are appended to
+        #[arg(long, default_value = history::DEFAULT_PATH)]
+        history: PathBuf,
+        /// Do not record this run in the benchmark history
+        #[arg(long, conflicts_with = "history")]
+        no_history: bool,
+    },
+    /// Compare the latest benchmark run against a previous run and flag regressions
+    Compare {
+        /// Slowdown of the median in percent that counts as a regression
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Commit to compare against, defaults to the latest run of a different commit
+        #[arg(short, long)]
+        baseline: Option<String>,
+        #[arg(long, default_value = history::DEFAULT_PATH)]
+        history: PathBuf,
+    },
+    /// Download the puzzle input of a day, or of every registered day of a year
+    Fetch {
+        year: u16,
+        day: Option<u8>,
+        /// Download the input again even if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Generate the module, README and registry entry of a new day
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Write a random input of a day, the same seed always gives the same input
+    Generate {
+        year: u16,
+        day: u8,
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// Number of lines, games or cards, or the side length of a grid
+        #[arg(short, long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        size: Option<usize>,
+        /// Between 0 and 1, e.g. the share of gears or how often scratch card numbers win
+        #[arg(short, long, value_parser = parse_density)]
+        density: Option<f64>,
+        /// Write the input to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Re-run the tests and solution of a day whenever its directory or input changes
+    Watch {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from this file instead, it is watched as well
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Submit the answer of a part, computed from the default input unless given
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: Option<String>,
+        /// Wait and try again once when the server asks to wait before answering
+        #[arg(long)]
+        wait: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn parse_density(value: &str) -> Result<f64, String> {
+    let density: f64 = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))?;
+
+    if (0.0..=1.0).contains(&density) {
+        Ok(density)
+    } else {
+        Err(format!("{density} is not between 0 and 1"))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            format,
+            explain,
+        } => {
+            let source = input
+                .as_deref()
+                .map_or(InputSource::Default, InputSource::from);
+            run(year, day, part, &source, format, explain)
+        }
+        Command::List => {
+            for puzzle in registry::PUZZLES {
+                println!("{puzzle}");
+            }
+
+            ExitCode::SUCCESS
+        }
+        Command::Verify { year, day } => verify(year, day),
+        Command::Examples { year, day } => examples(year, day),
+        Command::Bench {
+            year,
+            day,
+            warmup,
+            iterations,
+            input,
+            format,
+            history,
+            no_history,
+        } => {
+            let options = bench::Options { warmup, iterations };
+            let source = input
+                .as_deref()
+                .map_or(InputSource::Default, InputSource::from);
+            let history = (!no_history).then_some(history.as_path());
+            bench(year, day, &options, &source, format, history)
+        }
+        Command::Compare {
+            threshold,
+            baseline,
+            history,
+        } => compare(&history, baseline.as_deref(), threshold),
+        Command::Fetch { year, day, force } => fetch(year, day, force),
+        Command::New { year, day } => match scaffold::create(Path::new(""), year, day) {
+            Ok(files) => {
+                for file in files {
+                    println!("Wrote {}", file.display());
+                }
+
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+            density,
+            output,
+        } => {
+            let options = generate::Options { size, density };
+            generate(year, day, seed, &options, output.as_deref())
+        }
+        Command::Watch { year, day, input } => watch(year, day, input.as_deref()),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            wait,
+        } => submit(year, day, part, answer, wait),
+    }
+}
+
+fn run(
+    year: u16,
+    day: Option<u8>,
+    part: Option<u8>,
+    source: &InputSource,
+    format: Format,
+    explain: bool,
+) -> ExitCode {
+    let puzzles = registry::select(Some(year), day);
+
+    if puzzles.is_empty() {
+        match day {
+            Some(day) => eprintln!("No solution registered for {year}-{day:02}"),
+            None => eprintln!("No solutions registered for {year}"),
+        }
+
+        return ExitCode::FAILURE;
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut records = vec![];
+
+    for puzzle in puzzles {
+        let input = input::load(puzzle.year, puzzle.day, source).map_err(|err| err.to_string());
+
+        // a whole year mixes days with and without explanations, the answers still count
+        let explainer = explain.then(|| puzzle.explainer()).flatten();
+        if explain && explainer.is_none() {
+            eprintln!("{puzzle} cannot explain its answers, printing them only");
+        }
+
+        for part in parts.iter() {
+            let timed = input
+                .as_ref()
+                .map_err(Clone::clone)
+                .and_then(|input| puzzle.run(input, *part));
+
+            if let (Some(explainer), Ok(input)) = (explainer, &input) {
+                if let Err(err) = explainer(input, *part, Style::detect(), &mut io::stdout()) {
+                    eprintln!("could not write the explanation: {err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            if let Format::Text = format {
+                match &timed {
+                    Ok(timed) => println!(
+                        "{puzzle} part {part}: {} (parse {:.2?}, solve {:.2?})",
+                        timed.answer, timed.parse, timed.solve
+                    ),
+                    Err(err) => eprintln!("{puzzle} part {part}: {err}"),
+                }
+            }
+
+            if timed.is_err() {
+                exit_code = ExitCode::FAILURE;
+            }
+
+            records.push(solution::Record {
+                year: puzzle.year,
+                day: puzzle.day,
+                part: *part,
+                input: source.name(puzzle.year, puzzle.day),
+                error: timed.as_ref().err().cloned(),
+                timed: timed.ok(),
+            });
+        }
+    }
+
+    if let Format::Json = format {
+        let json = serde_json::to_string_pretty(&records).expect("records should serialize");
+        println!("{json}");
+    }
+
+    exit_code
+}
+
+fn verify(year: Option<u16>, day: Option<u8>) -> ExitCode {
+    let puzzles = registry::select(year, day);
+
+    if puzzles.is_empty() {
+        eprintln!("No solutions registered");
+        return ExitCode::FAILURE;
+    }
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    print_check_header();
+
+    for puzzle in puzzles {
+        for check in verify::verify(puzzle) {
+            if !print_check(&puzzle.to_string(), &check) {
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    exit_code
+}
+
+fn examples(year: Option<u16>, day: Option<u8>) -> ExitCode {
+    let puzzles = registry::select(year, day);
+
+    if puzzles.is_empty() {
+        eprintln!("No solutions registered");
+        return ExitCode::FAILURE;
+    }
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    print_check_header();
+
+    for puzzle in puzzles {
+        let path = examples::path(Path::new(""), puzzle.year, puzzle.day);
+
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|readme| examples::parse(&readme));
+
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("{}: {err}", path.display());
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for example in parsed.iter() {
+            for check in examples::check(puzzle, example) {
+                if !print_check(&format!("{puzzle}:{}", example.line), &check) {
+                    exit_code = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    exit_code
+}
+
+fn print_check_header() {
+    println!(
+        "{:<10} {:<4} {:<7} {:<20} Expected",
+        "Puzzle", "Part", "Status", "Answer"
+    );
+}
+
+/// Prints a row of the check table, returns false if the check failed
+fn print_check(name: &str, check: &verify::Check) -> bool {
+    let status = check.status();
+
+    let answer = match &check.answer {
+        Ok(answer) => answer,
+        Err(err) => err,
+    };
+
+    println!(
+        "{:<10} {:<4} {:<7} {:<20} {}",
+        name,
+        check.part,
+        status,
+        answer,
+        check.expected.as_deref().unwrap_or("-")
+    );
+
+    !matches!(status, verify::Status::Fail | verify::Status::Error)
+}
+
+fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    options: &bench::Options,
+    source: &InputSource,
+    format: Format,
+    history: Option<&Path>,
+) -> ExitCode {
+    let puzzles = registry::select(year, day);
+
+    if puzzles.is_empty() {
+        eprintln!("No solutions registered");
+        return ExitCode::FAILURE;
+    }
+
+    if cfg!(debug_assertions) {
+        eprintln!("Benchmarking a debug build, use `cargo run --release` for meaningful numbers");
+    }
+
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut records = vec![];
+
+    if let Format::Text = format {
+        println!(
+            "{:<8} {:<5} {:>12} {:>12} {:>12} {:>12} {:>12}",
+            "Puzzle", "Phase", "Median", "p95", "Min", "Max", "Throughput"
+        );
+    }
+
+    for puzzle in puzzles {
+        let stats = input::load(puzzle.year, puzzle.day, source)
+            .map_err(|err| err.to_string())
+            .and_then(|input| Ok((input.len(), puzzle.bench(&input, options)?)));
+
+        let (bytes, stats) = match stats {
+            Ok(stats) => stats,
+            Err(err) => {
+                eprintln!("{puzzle}: {err}");
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        for stats in stats {
+            let record = bench::Record::new(
+                puzzle.year,
+                puzzle.day,
+                source.name(puzzle.year, puzzle.day),
+                bytes as u64,
+                stats,
+            );
+
+            if let Format::Text = format {
+                let stats = &record.stats;
+                println!(
+                    "{:<8} {:<5} {:>12} {:>12} {:>12} {:>12} {:>12}",
+                    puzzle.to_string(),
+                    stats.phase,
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.p95),
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.max),
+                    record.throughput(),
+                );
+            }
+
+            records.push(record);
+        }
+    }
+
+    if let Format::Json = format {
+        let json = serde_json::to_string_pretty(&records).expect("records should serialize");
+        println!("{json}");
+    }
+
+    if let Some(path) = history {
+        if let Err(err) = history::append(path, &history::Entry::new(records)) {
+            eprintln!("{err}");
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    exit_code
+}
+
+fn compare(path: &Path, commit: Option<&str>, threshold: f64) -> ExitCode {
+    let entries = match history::load(path) {
+        Ok(entries) => entries,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some(current) = entries.last() else {
+        eprintln!("No benchmark runs recorded in {}", path.display());
+        return ExitCode::FAILURE;
+    };
+
+    let Some(baseline) = history::baseline(&entries, commit) else {
+        eprintln!(
+            "No baseline run on the same input to compare {} against",
+            current.commit
+        );
+        return ExitCode::FAILURE;
+    };
+
+    println!(
+        "Comparing {} against {} (threshold {threshold}%)",
+        current.commit, baseline.commit
+    );
+    println!(
+        "{:<8} {:<5} {:>12} {:>12} {:>9}",
+        "Puzzle", "Phase", "Baseline", "Current", "Change"
+    );
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for comparison in history::compare(baseline, current) {
+        let regression = comparison.is_regression(threshold);
+
+        if regression {
+            exit_code = ExitCode::FAILURE;
+        }
+
+        println!(
+            "{:<8} {:<5} {:>12} {:>12} {:>+8.1}%{}",
+            format!("{}-{:02}", comparison.year, comparison.day),
+            comparison.phase,
+            format!("{:.2?}", comparison.baseline),
+            format!("{:.2?}", comparison.current),
+            comparison.change(),
+            if regression { "  REGRESSION" } else { "" }
+        );
+    }
+
+    exit_code
+}
+
+/// Client for adventofcode.com, `AOC_BASE_URL` points it to a different server
+fn client() -> Result<client::Client, client::ClientError> {
+    let session = client::session()?;
+    let base_url = std::env::var("AOC_BASE_URL").unwrap_or(client::BASE_URL.to_string());
+
+    Ok(client::Client::new(
+        &base_url,
+        &session,
+        client::DEFAULT_THROTTLE,
+    ))
+}
+
+fn fetch(year: u16, day: Option<u8>, force: bool) -> ExitCode {
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => registry::select(Some(year), None)
+            .iter()
+            .map(|puzzle| puzzle.day)
+            .collect(),
+    };
+
+    let mut client = match client() {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for day in days {
+        let path = input::default_path(year, day);
+
+        match fetch::fetch(&mut client, year, day, &path, force) {
+            Ok(fetch::Fetched::Cached) => {
+                println!("{year}-{day:02}: {} already exists", path.display())
+            }
+            Ok(fetch::Fetched::Downloaded) => {
+                println!("{year}-{day:02}: downloaded to {}", path.display())
+            }
+            Err(err) => {
+                eprintln!("{year}-{day:02}: {err}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    exit_code
+}
+
+fn generate(
+    year: u16,
+    day: u8,
+    seed: u64,
+    options: &generate::Options,
+    output: Option<&Path>,
+) -> ExitCode {
+    let Some(puzzle) = registry::select(Some(year), Some(day)).pop() else {
+        eprintln!("No solution registered for {year}-{day:02}");
+        return ExitCode::FAILURE;
+    };
+
+    let Some(generator) = puzzle.generator() else {
+        eprintln!("{puzzle} has no input generator");
+        return ExitCode::FAILURE;
+    };
+
+    let out: Box<dyn Write> = match output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(err) => {
+                eprintln!("could not create {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdout().lock()),
+    };
+
+    let mut out = BufWriter::new(out);
+    let mut rng = generate::Rng::new(seed);
+
+    match generator(&mut rng, options, &mut out).and_then(|_| out.flush()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{puzzle}: could not write input: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn watch(year: u16, day: u8, input: Option<&Path>) -> ExitCode {
+    let directory = PathBuf::from(format!("{year}/{day:02}"));
+
+    if !directory.is_dir() {
+        eprintln!("{} does not exist", directory.display());
+        return ExitCode::FAILURE;
+    }
+
+    let mut paths = vec![directory];
+    paths.extend(input.map(Path::to_path_buf));
+
+    let watcher = match watch::Watcher::new(&paths) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // the day's source changes between runs, so every run goes through cargo to rebuild it
+    let cargo = std::env::var_os("CARGO").unwrap_or("cargo".into());
+    let mut previous = vec![];
+
+    loop {
+        let tests = process::Command::new(&cargo)
+            .args(["test", "--quiet", "--lib", &format!("y{year}::day{day:02}")])
+            .status();
+
+        match tests {
+            Ok(status) if status.success() => println!("Tests passed"),
+            Ok(_) => println!("Tests failed"),
+            Err(err) => eprintln!("could not run cargo test: {err}"),
+        }
+
+        let mut run = process::Command::new(&cargo);
+        run.args([
+            "run",
+            "--quiet",
+            "--",
+            "run",
+            &year.to_string(),
+            &day.to_string(),
+        ])
+        .args(["--format", "json"])
+        .stderr(Stdio::null());
+
+        if let Some(input) = input {
+            run.arg("--input").arg(input);
+        }
+
+        let records = run
+            .output()
+            .map_err(|err| err.to_string())
+            .and_then(|output| {
+                serde_json::from_slice::<Vec<solution::Record>>(&output.stdout)
+                    .map_err(|_| "does not build".to_string())
+            });
+
+        match records {
+            Ok(records) => {
+                for record in records.iter() {
+                    let change = match watch::compare(&previous, record) {
+                        watch::Change::New => String::new(),
+                        watch::Change::Unchanged => " (unchanged)".to_string(),
+                        watch::Change::Changed(before) => format!(" (was {before})"),
+                    };
+
+                    println!(
+                        "{year}-{day:02} part {}: {}{change}",
+                        record.part,
+                        watch::outcome(record)
+                    );
+                }
+
+                previous = records;
+            }
+            Err(err) => println!("{year}-{day:02}: {err}"),
+        }
+
+        println!("Waiting for changes, press Ctrl+C to stop");
+
+        match watcher.wait(watch::DEFAULT_QUIET) {
+            Ok(changed) => {
+                for path in changed {
+                    println!("Changed {}", path.display());
+                }
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+}
+
+fn submit(year: u16, day: u8, part: u8, answer: Option<String>, wait: bool) -> ExitCode {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let Some(puzzle) = registry::select(Some(year), Some(day)).pop() else {
+                eprintln!("No solution registered for {year}-{day:02}, pass the answer explicitly");
+                return ExitCode::FAILURE;
+            };
+
+            let answer = input::load(year, day, &InputSource::Default)
+                .map_err(|err| err.to_string())
+                .and_then(|input| puzzle.solve(&input, part));
+
+            match answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!("{puzzle} part {part}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let path = answers::path(year, day);
+
+    let mut answers = match answers::Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut client = match client() {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Submitting {answer} for {year}-{day:02} part {part}");
+
+    let mut outcome = submit::submit(
+        &mut client,
+        &mut answers,
+        verify::INPUT_NAME,
+        (year, day, part),
+        &answer,
+    );
+
+    if let (true, Ok(submit::Outcome::Wait(duration))) = (wait, &outcome) {
+        println!("Waiting {}s before trying again", duration.as_secs());
+        std::thread::sleep(*duration);
+        outcome = submit::submit(
+            &mut client,
+            &mut answers,
+            verify::INPUT_NAME,
+            (year, day, part),
+            &answer,
+        );
+    }
+
+    let outcome = match outcome {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Answer is {outcome}");
+
+    let recorded = matches!(
+        outcome,
+        submit::Outcome::Correct | submit::Outcome::Wrong(_)
+    );
+
+    if recorded {
+        if let Err(err) = answers.save(&path) {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match outcome {
+        submit::Outcome::Correct | submit::Outcome::AlreadySolved => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
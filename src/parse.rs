@@ -0,0 +1,360 @@
+use crate::error::ParseError;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Combines single decimal digits into the number they spell, `[4, 6, 7]` becomes `467`
+pub fn number_from_digits(digits: &[usize]) -> usize {
+    digits.iter().fold(0, |number, digit| number * 10 + digit)
+}
+
+/// Tokenizer over a single line of puzzle input, every token skips the spaces in front of it
+///
+/// ```
+/// use advent_of_code::parse::Cursor;
+///
+/// let mut cursor = Cursor::new(1, "Card 3: 41 48 | 83 86");
+/// let id: u32 = cursor.header("Card").unwrap();
+/// let winning: Vec<u32> = cursor.many_until("|", Cursor::unsigned).unwrap();
+/// cursor.literal("|").unwrap();
+/// let numbers: Vec<u32> = cursor.many(Cursor::unsigned).unwrap();
+///
+/// assert_eq!((3, vec![41, 48], vec![83, 86]), (id, winning, numbers));
+/// ```
+pub struct Cursor<'a> {
+    line: usize,
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a cursor at the start of `source`, which is line `line` of the input
+    pub fn new(line: usize, source: &'a str) -> Self {
+        Self {
+            line,
+            source,
+            position: 0,
+        }
+    }
+
+    /// The part of the line that has not been consumed yet
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    pub fn is_at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().is_empty()
+    }
+
+    /// Error for the bytes `span` of the line
+    pub fn error(&self, span: Range<usize>, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.source, span, expected)
+    }
+
+    /// Error for the token in front of the cursor
+    fn error_here(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest();
+        let length = match rest.chars().next() {
+            None => 0,
+            Some(char) if char.is_alphanumeric() => rest
+                .find(|char: char| !char.is_alphanumeric())
+                .unwrap_or(rest.len()),
+            Some(char) => char.len_utf8(),
+        };
+
+        self.error(self.position..self.position + length, expected)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `literal` if it comes next
+    pub fn eat(&mut self, literal: &str) -> bool {
+        self.skip_whitespace();
+
+        if self.rest().starts_with(literal) {
+            self.position += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error_here(format!("`{literal}`")))
+        }
+    }
+
+    /// Consumes a run of alphanumeric characters
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+        let length = rest
+            .find(|char: char| !char.is_alphanumeric())
+            .unwrap_or(rest.len());
+
+        if length == 0 {
+            return Err(self.error_here("a word"));
+        }
+
+        self.position += length;
+        Ok(&rest[..length])
+    }
+
+    /// Consumes a word and returns the value it is mapped to in `choices`
+    pub fn one_of<T: Copy>(&mut self, choices: &[(&str, T)]) -> Result<T, ParseError> {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let word = self.word().unwrap_or_default();
+
+        match choices.iter().find(|(choice, _)| *choice == word) {
+            Some((_, value)) => Ok(*value),
+            None => {
+                self.position = start;
+
+                let names: Vec<_> = choices
+                    .iter()
+                    .map(|(name, _)| format!("`{name}`"))
+                    .collect();
+                let expected = match names.split_last() {
+                    Some((last, [])) => last.clone(),
+                    Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+                    None => "nothing".to_string(),
+                };
+
+                Err(self.error_here(expected))
+            }
+        }
+    }
+
+    /// Consumes a number without a sign, e.g. `42`
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.integer(false, "an unsigned integer")
+    }
+
+    /// Consumes a number with an optional sign, e.g. `-7` or `+3`
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.integer(true, "an integer")
+    }
+
+    fn integer<T: FromStr>(&mut self, signed: bool, expected: &str) -> Result<T, ParseError> {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+        let sign = if signed && rest.starts_with(['-', '+']) {
+            1
+        } else {
+            0
+        };
+        let length = rest[sign..]
+            .find(|char: char| !char.is_ascii_digit())
+            .map_or(rest.len(), |length| sign + length);
+        let followed_by_word = rest[length..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphanumeric);
+
+        if length == sign || followed_by_word {
+            return Err(self.error_here(expected));
+        }
+
+        // the digits are valid, so this can only fail if the number does not fit into `T`
+        let number = rest[..length]
+            .parse()
+            .map_err(|_| self.error(self.position..self.position + length, expected))?;
+
+        self.position += length;
+        Ok(number)
+    }
+
+    /// Consumes a labelled header like `Card 12:` and returns its number
+    pub fn header<T: FromStr>(&mut self, label: &str) -> Result<T, ParseError> {
+        self.literal(label)?;
+
+        let number = self.labelled(
+            &format!("a {} number", label.to_lowercase()),
+            Self::unsigned,
+        )?;
+        self.literal(":")?;
+
+        Ok(number)
+    }
+
+    /// Consumes `<key><separator><value>`, an empty separator only requires whitespace in between
+    pub fn key_value<K, V>(
+        &mut self,
+        key: impl FnOnce(&mut Self) -> Result<K, ParseError>,
+        separator: &str,
+        value: impl FnOnce(&mut Self) -> Result<V, ParseError>,
+    ) -> Result<(K, V), ParseError> {
+        let key = key(self)?;
+        self.literal(separator)?;
+        let value = value(self)?;
+
+        Ok((key, value))
+    }
+
+    /// Consumes one or more items separated by `separator`
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Consumes whitespace separated items until the end of the line
+    pub fn many<T>(
+        &mut self,
+        item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.many_until("", item)
+    }
+
+    /// Consumes whitespace separated items until `terminator` or the end of the line, the
+    /// terminator itself is not consumed
+    pub fn many_until<T>(
+        &mut self,
+        terminator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![];
+
+        while !self.is_at_end() && (terminator.is_empty() || !self.rest().starts_with(terminator)) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Runs `item` and replaces what its error expected with `expected`
+    pub fn labelled<T>(
+        &mut self,
+        expected: &str,
+        item: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        item(self).map_err(|mut err| {
+            err.expected = expected.to_string();
+            err
+        })
+    }
+
+    /// Runs `item` and returns its value together with the bytes of the line it consumed
+    pub fn spanned<T>(
+        &mut self,
+        item: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<(T, Range<usize>), ParseError> {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let value = item(self)?;
+
+        Ok((value, start..self.position))
+    }
+
+    /// Fails unless everything but trailing whitespace has been consumed
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error_here("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_parse {
+    use super::{number_from_digits, Cursor};
+
+    #[test]
+    fn test_number_from_digits() {
+        assert_eq!(467, number_from_digits(&[4, 6, 7]));
+        assert_eq!(8, number_from_digits(&[0, 8]));
+        assert_eq!(0, number_from_digits(&[]));
+    }
+
+    #[test]
+    fn test_cursor_tokens() {
+        let mut cursor = Cursor::new(1, "  add -12 to +3, 40;");
+
+        assert_eq!(Ok("add"), cursor.word());
+        assert_eq!(Ok(-12), cursor.signed::<i32>());
+        assert!(cursor.eat("to"));
+        assert_eq!(Ok(3), cursor.signed::<i32>());
+        assert!(!cursor.eat(";"));
+        cursor.literal(",").unwrap();
+        assert_eq!(Ok(40), cursor.unsigned::<u8>());
+        cursor.literal(";").unwrap();
+        cursor.end().unwrap();
+    }
+
+    #[test]
+    fn test_cursor_lists() {
+        let mut cursor = Cursor::new(1, "a=1, b=2; 3  4 5");
+
+        let pairs = cursor
+            .separated(",", |cursor| {
+                cursor.key_value(Cursor::word, "=", Cursor::unsigned::<u32>)
+            })
+            .unwrap();
+        assert_eq!(vec![("a", 1), ("b", 2)], pairs);
+
+        cursor.literal(";").unwrap();
+        assert_eq!(
+            Ok(vec![3, 4]),
+            cursor.many_until("5", Cursor::unsigned::<u32>)
+        );
+        assert_eq!(Ok(vec![5]), cursor.many(Cursor::unsigned::<u32>));
+        assert!(cursor.is_at_end());
+
+        let mut cursor = Cursor::new(1, "x  42 y");
+        cursor.literal("x").unwrap();
+        assert_eq!(Ok((42, 3..5)), cursor.spanned(Cursor::unsigned::<u32>));
+    }
+
+    #[test]
+    fn test_cursor_errors() {
+        let err = Cursor::new(4, "Card x:").header::<u32>("Card").unwrap_err();
+        assert_eq!((4, 6), (err.line, err.column()));
+        assert_eq!("a card number", err.expected);
+
+        let err = Cursor::new(1, "12ab").unsigned::<u32>().unwrap_err();
+        assert_eq!("`12ab`", err.found);
+
+        let err = Cursor::new(1, "-3").unsigned::<u32>().unwrap_err();
+        assert_eq!("`-`", err.found);
+
+        let err = Cursor::new(1, "300").unsigned::<u8>().unwrap_err();
+        assert_eq!("`300`", err.found);
+
+        let colors = [("red", 0), ("green", 1), ("blue", 2)];
+        let err = Cursor::new(1, " purple").one_of(&colors).unwrap_err();
+        assert_eq!((2, "`purple`"), (err.column(), err.found.as_str()));
+        assert_eq!("`red`, `green` or `blue`", err.expected);
+        assert_eq!(
+            "end of line",
+            Cursor::new(1, "").one_of(&colors).unwrap_err().found
+        );
+
+        let err = Cursor::new(1, "1 2 |")
+            .many::<u32>(Cursor::unsigned)
+            .unwrap_err();
+        assert_eq!((5, "`|`"), (err.column(), err.found.as_str()));
+
+        let err = Cursor::new(1, "1 |").end().unwrap_err();
+        assert_eq!("end of line", err.expected);
+    }
+}
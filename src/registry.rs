@@ -0,0 +1,105 @@
+use crate::bench;
+use crate::explain::Explainer;
+use crate::generate::Generator;
+use crate::solution::{self, Solution, Timed};
+use crate::y2023;
+use std::fmt::{self, Display};
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    run: fn(&str, u8) -> Result<Timed, String>,
+    bench: fn(&str, &bench::Options) -> Result<Vec<bench::Stats>, String>,
+    generate: Option<Generator>,
+    explain: Option<Explainer>,
+}
+
+impl Puzzle {
+    const fn new<S: Solution>(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            run: solution::run::<S>,
+            bench: bench::bench::<S>,
+            generate: S::GENERATE,
+            explain: S::EXPLAIN,
+        }
+    }
+
+    pub fn solve(&self, input: &str, part: u8) -> Result<String, String> {
+        self.run(input, part).map(|timed| timed.answer)
+    }
+
+    /// Solves a part and measures how long parsing and solving took
+    pub fn run(&self, input: &str, part: u8) -> Result<Timed, String> {
+        (self.run)(input, part)
+    }
+
+    pub fn bench(
+        &self,
+        input: &str,
+        options: &bench::Options,
+    ) -> Result<Vec<bench::Stats>, String> {
+        (self.bench)(input, options)
+    }
+
+    pub fn generator(&self) -> Option<Generator> {
+        self.generate
+    }
+
+    pub fn explainer(&self) -> Option<Explainer> {
+        self.explain
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}", self.year, self.day)
+    }
+}
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<y2023::day01::Day01>(2023, 1),
+    Puzzle::new::<y2023::day02::Day02>(2023, 2),
+    Puzzle::new::<y2023::day03::Day03>(2023, 3),
+    Puzzle::new::<y2023::day04::Day04>(2023, 4),
+];
+
+/// Every puzzle matching the given year and day, `None` matches everything
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .filter(|puzzle| year.is_none_or(|year| puzzle.year == year))
+        .filter(|puzzle| day.is_none_or(|day| puzzle.day == day))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_registry {
+    use super::{select, PUZZLES};
+
+    #[test]
+    fn test_puzzles_are_unique_and_sorted() {
+        for pair in PUZZLES.windows(2) {
+            assert!(
+                (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day),
+                "{}-{:02} should come before {}-{:02}",
+                pair[0].year,
+                pair[0].day,
+                pair[1].year,
+                pair[1].day
+            );
+        }
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(PUZZLES.len(), select(None, None).len());
+        assert!(select(Some(2023), None)
+            .iter()
+            .all(|puzzle| puzzle.year == 2023));
+        assert_eq!(1, select(Some(2023), Some(3)).len());
+        assert_eq!("2023-03", select(Some(2023), Some(3))[0].to_string());
+        assert!(select(Some(1999), None).is_empty());
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+
+const MOD_TEMPLATE: &str = include_str!("../templates/mod.rs.tmpl");
+const README_TEMPLATE: &str = include_str!("../templates/README.md.tmpl");
+
+const LIB_PATH: &str = "src/lib.rs";
+const REGISTRY_PATH: &str = "src/registry.rs";
+const PUZZLES_START: &str = "pub const PUZZLES: &[Puzzle] = &[\n";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    /// A source file the new day has to be registered in does not look as expected
+    UnexpectedSource(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::UnexpectedSource(path) => {
+                write!(
+                    f,
+                    "could not find where to register the day in {}",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io(path, err) => {
+                write!(f, "could not access {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &format!("{day:02}"))
+        .replace("{{day_number}}", &day.to_string())
+}
+
+/// Inserts `new` into the block of `#[path]` module declarations, keeping them sorted
+fn add_module(source: &str, new: &str) -> String {
+    let mut blocks: Vec<String> = source
+        .split("#[path")
+        .filter(|block| !block.trim().is_empty())
+        .map(|block| format!("#[path{}", block.trim_end()))
+        .collect();
+
+    blocks.push(new.trim_end().to_string());
+    blocks.sort();
+    blocks.dedup();
+
+    blocks.join("\n") + "\n"
+}
+
+fn add_year_module(lib: &str, year: u16) -> Option<String> {
+    let module = format!("#[path = \"../{year}/mod.rs\"]\npub mod y{year};\n");
+
+    if lib.contains(&module) {
+        return Some(lib.to_string());
+    }
+
+    // the year modules are a block of `#[path]` declarations after the other modules
+    let start = lib.find("#[path")?;
+    let end = lib[start..]
+        .find("\n\n")
+        .map_or(lib.trim_end().len(), |end| start + end);
+
+    Some(format!(
+        "{}{}{}",
+        &lib[..start],
+        add_module(&lib[start..end], &module).trim_end(),
+        &lib[end..]
+    ))
+}
+
+fn add_day_module(year_module: &str, day: u8) -> String {
+    add_module(
+        year_module,
+        &format!("#[path = \"{day:02}/mod.rs\"]\npub mod day{day:02};\n"),
+    )
+}
+
+/// Adds the day to the `PUZZLES` list of the registry, sorted by year and day, and imports the
+/// year module if it is new
+fn add_puzzle(registry: &str, year: u16, day: u8) -> Option<String> {
+    let import = format!("use crate::y{year};\n");
+
+    let registry = if registry.contains(&import) {
+        registry.to_string()
+    } else {
+        // keep the year imports next to each other so they stay sorted
+        let position = registry
+            .rfind("\nuse crate::y")
+            .or_else(|| registry.rfind("\nuse "))?
+            + 1;
+        let position = position + registry[position..].find('\n')? + 1;
+
+        format!(
+            "{}{}{}",
+            &registry[..position],
+            import,
+            &registry[position..]
+        )
+    };
+
+    let start = registry.find(PUZZLES_START)? + PUZZLES_START.len();
+    let end = start + registry[start..].find("];")?;
+
+    let mut entries: Vec<((u16, u8), String)> = registry[start..end]
+        .lines()
+        .map(|line| {
+            let (_, args) = line.trim().rsplit_once(">(")?;
+            let (year, day) = args.trim_end_matches("),").split_once(", ")?;
+            Some(((year.parse().ok()?, day.parse().ok()?), line.to_string()))
+        })
+        .collect::<Option<_>>()?;
+
+    entries.push((
+        (year, day),
+        format!("    Puzzle::new::<y{year}::day{day:02}::Day{day:02}>({year}, {day}),"),
+    ));
+    entries.sort();
+    entries.dedup_by_key(|(key, _)| *key);
+
+    let lines: Vec<_> = entries.into_iter().map(|(_, line)| line).collect();
+
+    Some(format!(
+        "{}{}\n{}",
+        &registry[..start],
+        lines.join("\n"),
+        &registry[end..]
+    ))
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(content),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(ScaffoldError::Io(path.to_path_buf(), err)),
+    }
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    let io_err = |err| ScaffoldError::Io(path.to_path_buf(), err);
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_err)?;
+    }
+
+    std::fs::write(path, content).map_err(io_err)
+}
+
+/// Creates the module and README of a new day below `root` and registers it, returning the
+/// files that were written
+pub fn create(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let day_dir = root.join(format!("{year}/{day:02}"));
+    let module_path = day_dir.join("mod.rs");
+
+    if module_path.exists() {
+        return Err(ScaffoldError::AlreadyExists(module_path));
+    }
+
+    let lib_path = root.join(LIB_PATH);
+    let lib = add_year_module(&read(&lib_path)?, year)
+        .ok_or(ScaffoldError::UnexpectedSource(lib_path.clone()))?;
+
+    let registry_path = root.join(REGISTRY_PATH);
+    let registry = add_puzzle(&read(&registry_path)?, year, day)
+        .ok_or(ScaffoldError::UnexpectedSource(registry_path.clone()))?;
+
+    let year_path = root.join(format!("{year}/mod.rs"));
+    let year_module = add_day_module(&read(&year_path)?, day);
+
+    let readme_path = day_dir.join("README.md");
+
+    let files = [
+        (module_path, render(MOD_TEMPLATE, year, day)),
+        (readme_path, render(README_TEMPLATE, year, day)),
+        (year_path, year_module),
+        (lib_path, lib),
+        (registry_path, registry),
+    ];
+
+    for (path, content) in files.iter() {
+        write(path, content)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod test_scaffold {
+    use super::{add_day_module, add_puzzle, add_year_module, render, README_TEMPLATE};
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            "# 2023-05\n\nLink: https://adventofcode.com/2023/day/5\n\n## Examples\n\n```example part1= part2=\n```\n",
+            render(README_TEMPLATE, 2023, 5)
+        );
+    }
+
+    const TEST_YEAR_MODULE: &str = "\
+#[path = \"01/mod.rs\"]
+pub mod day01;
+#[path = \"03/mod.rs\"]
+pub mod day03;
+";
+
+    #[test]
+    fn test_add_day_module() {
+        assert_eq!(
+            "\
+#[path = \"01/mod.rs\"]
+pub mod day01;
+#[path = \"02/mod.rs\"]
+pub mod day02;
+#[path = \"03/mod.rs\"]
+pub mod day03;
+",
+            add_day_module(TEST_YEAR_MODULE, 2)
+        );
+
+        assert_eq!(
+            "#[path = \"01/mod.rs\"]\npub mod day01;\n",
+            add_day_module("", 1)
+        );
+    }
+
+    const TEST_LIB: &str = "\
+pub mod verify;
+
+#[path = \"../2023/mod.rs\"]
+pub mod y2023;
+";
+
+    #[test]
+    fn test_add_year_module() {
+        assert_eq!(
+            "\
+pub mod verify;
+
+#[path = \"../2023/mod.rs\"]
+pub mod y2023;
+#[path = \"../2024/mod.rs\"]
+pub mod y2024;
+",
+            add_year_module(TEST_LIB, 2024).unwrap()
+        );
+
+        assert_eq!(TEST_LIB, add_year_module(TEST_LIB, 2023).unwrap());
+    }
+
+    const TEST_REGISTRY: &str = "\
+use crate::solution::{self, Solution};
+use crate::y2023;
+use std::fmt::{self, Display};
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<y2023::day01::Day01>(2023, 1),
+    Puzzle::new::<y2023::day03::Day03>(2023, 3),
+];
+";
+
+    #[test]
+    fn test_add_puzzle() {
+        assert_eq!(
+            "\
+use crate::solution::{self, Solution};
+use crate::y2023;
+use crate::y2024;
+use std::fmt::{self, Display};
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<y2023::day01::Day01>(2023, 1),
+    Puzzle::new::<y2023::day02::Day02>(2023, 2),
+    Puzzle::new::<y2023::day03::Day03>(2023, 3),
+    Puzzle::new::<y2024::day01::Day01>(2024, 1),
+];
+",
+            add_puzzle(&add_puzzle(TEST_REGISTRY, 2024, 1).unwrap(), 2023, 2).unwrap()
+        );
+
+        assert!(add_puzzle("const OTHER: &[u8] = &[];", 2023, 2).is_none());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::bench::nanos;
+use crate::explain::Explainer;
+use crate::generate::Generator;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// Common interface every day implements so the runner can treat them uniformly.
+///
+/// The puzzle input is parsed once into `Input` which is then shared by both parts. It may borrow
+/// the text of the input instead of copying it.
+pub trait Solution {
+    type Input<'a>;
+    type Error: Display;
+
+    /// Writes random inputs for benchmarks and fuzzing, days without one cannot be generated
+    const GENERATE: Option<Generator> = None;
+
+    /// Shows how the answers come about, used by `run --explain`
+    const EXPLAIN: Option<Explainer> = None;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error>;
+
+    fn part1(input: &Self::Input<'_>) -> impl Display;
+
+    fn part2(input: &Self::Input<'_>) -> impl Display;
+}
+
+/// Answer of a part together with how long parsing the input and solving the part took
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timed {
+    pub answer: String,
+    #[serde(rename = "parse_ns", with = "nanos")]
+    pub parse: Duration,
+    #[serde(rename = "solve_ns", with = "nanos")]
+    pub solve: Duration,
+}
+
+/// Outcome of running a single part, as printed by `run --format json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Path of the input file, `-` for stdin
+    pub input: String,
+    #[serde(flatten)]
+    pub timed: Option<Timed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Parses `input` and solves the given part, formatting the answer as a string
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<String, String> {
+    run::<S>(input, part).map(|timed| timed.answer)
+}
+
+/// Like `solve` but also measures how long parsing and solving took
+pub fn run<S: Solution>(input: &str, part: u8) -> Result<Timed, String> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|err| format!("could not parse input: {err}"))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1(&parsed).to_string(),
+        2 => S::part2(&parsed).to_string(),
+        part => return Err(format!("unknown part: {part}")),
+    };
+
+    Ok(Timed {
+        answer,
+        parse,
+        solve: start.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod test_solution {
+    use super::{run, solve, Record, Solution};
+    use std::fmt::Display;
+    use std::num::ParseIntError;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<i64>;
+        type Error = ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+            input.lines().map(|line| line.parse()).collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Display {
+            input.iter().sum::<i64>()
+        }
+
+        fn part2(input: &Self::Input<'_>) -> impl Display {
+            input.iter().product::<i64>()
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Ok("9".to_string()), solve::<Sum>("2\n3\n4", 1));
+        assert_eq!(Ok("24".to_string()), solve::<Sum>("2\n3\n4", 2));
+        assert!(solve::<Sum>("2\n3\n4", 3).is_err());
+        assert!(solve::<Sum>("2\nthree\n4", 1).is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let timed = run::<Sum>("2\n3\n4", 2).expect("should solve");
+        assert_eq!("24", timed.answer);
+
+        let record = Record {
+            year: 2023,
+            day: 1,
+            part: 2,
+            input: "2023/01/input.txt".to_string(),
+            timed: Some(timed.clone()),
+            error: None,
+        };
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!("24", json["answer"]);
+        assert_eq!(timed.parse.as_nanos() as u64, json["parse_ns"]);
+        assert!(json.get("error").is_none());
+
+        let record = Record {
+            timed: None,
+            error: run::<Sum>("x", 1).err(),
+            ..record
+        };
+        let json = serde_json::to_value(&record).unwrap();
+        assert!(json.get("answer").is_none());
+        assert!(json["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("could not parse input"));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::error::ParseError;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead};
+
+/// Error of a streaming solution, reading the input or parsing one of its lines failed
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "could not read input: {err}"),
+            StreamError::Parse(err) => write!(f, "could not parse input: {err}"),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+/// Calls `f` with the 1-based number and content of every line of `reader`
+///
+/// Only one line is held in memory at a time, line endings are stripped like `str::lines` does.
+pub fn for_each_line<R: BufRead>(
+    mut reader: R,
+    mut f: impl FnMut(usize, &str) -> Result<(), StreamError>,
+) -> Result<(), StreamError> {
+    let mut buffer = String::new();
+    let mut number = 0;
+
+    loop {
+        buffer.clear();
+
+        if reader.read_line(&mut buffer)? == 0 {
+            return Ok(());
+        }
+
+        number += 1;
+
+        let line = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        f(number, line)?;
+    }
+}
+
+#[cfg(test)]
+mod test_stream {
+    use super::{for_each_line, StreamError};
+    use crate::error::ParseError;
+
+    #[test]
+    fn test_for_each_line() {
+        let mut lines = vec![];
+
+        for_each_line("a\r\nb\n\nc".as_bytes(), |number, line| {
+            lines.push((number, line.to_string()));
+            Ok(())
+        })
+        .unwrap();
+
+        let expected = [(1, "a"), (2, "b"), (3, ""), (4, "c")];
+        assert_eq!(expected.map(|(n, l)| (n, l.to_string())).to_vec(), lines);
+    }
+
+    #[test]
+    fn test_errors() {
+        let result = for_each_line([b'a', b'\n', 0xff].as_slice(), |_, _| Ok(()));
+        assert!(matches!(result, Err(StreamError::Io(_))));
+
+        let result = for_each_line("a\nb".as_bytes(), |number, line| {
+            Err(ParseError::new(number, line, 0..1, "`c`").into())
+        });
+        assert!(matches!(result, Err(StreamError::Parse(err)) if err.line == 1));
+    }
+}
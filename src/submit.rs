@@ -0,0 +1,233 @@
+use crate::answers::{Answers, Hint, Judgement};
+use crate::client::{Client, ClientError};
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+/// Result of an answer submission as reported by the server
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong(Option<Hint>),
+    /// An answer was submitted too recently, the server asks to wait before trying again
+    Wait(Duration),
+    /// The part was already solved or is not unlocked yet
+    AlreadySolved,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong(Some(hint)) => write!(f, "wrong, the answer is {hint}"),
+            Outcome::Wrong(None) => write!(f, "wrong"),
+            Outcome::Wait(duration) => {
+                write!(f, "answered too recently, wait {}s", duration.as_secs())
+            }
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The answer was not submitted because the known answers already decide it
+    Refused(Judgement),
+    Client(ClientError),
+    UnexpectedResponse(String),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(Judgement::Correct) => {
+                write!(f, "answer is already known to be correct")
+            }
+            SubmitError::Refused(Judgement::Mismatch(correct)) => {
+                write!(f, "answer is wrong, the known correct answer is {correct}")
+            }
+            SubmitError::Refused(Judgement::Wrong(Some(hint))) => {
+                write!(f, "answer is known to be wrong, it is {hint}")
+            }
+            SubmitError::Refused(_) => write!(f, "answer is known to be wrong"),
+            SubmitError::Client(err) => err.fmt(f),
+            SubmitError::UnexpectedResponse(body) => {
+                write!(f, "could not understand the response: {}", body.trim())
+            }
+        }
+    }
+}
+
+/// Submits an answer unless the known answers already decide it, and records a correct or
+/// wrong outcome in `answers`
+pub fn submit(
+    client: &mut Client,
+    answers: &mut Answers,
+    input: &str,
+    (year, day, part): (u16, u8, u8),
+    answer: &str,
+) -> Result<Outcome, SubmitError> {
+    match answers.judge(input, part, answer) {
+        Judgement::Unknown => {}
+        judgement => return Err(SubmitError::Refused(judgement)),
+    }
+
+    let body = client
+        .answer(year, day, part, answer)
+        .map_err(SubmitError::Client)?;
+    let outcome = parse_response(&body).ok_or(SubmitError::UnexpectedResponse(body))?;
+
+    match outcome {
+        Outcome::Correct => answers.set(input, part, answer),
+        Outcome::Wrong(hint) => answers.add_wrong(input, part, answer, hint),
+        Outcome::Wait(_) | Outcome::AlreadySolved => {}
+    }
+
+    Ok(outcome)
+}
+
+/// Interprets the HTML page returned after posting an answer
+pub fn parse_response(body: &str) -> Option<Outcome> {
+    let message = article(body).unwrap_or(body);
+
+    if message.contains("That's the right answer") {
+        return Some(Outcome::Correct);
+    }
+
+    if message.contains("That's not the right answer") {
+        let hint = if message.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+
+        return Some(Outcome::Wrong(hint));
+    }
+
+    if message.contains("You gave an answer too recently") {
+        return Some(Outcome::Wait(
+            parse_wait(message).unwrap_or(Duration::from_secs(60)),
+        ));
+    }
+
+    if message.contains("You don't seem to be solving the right level") {
+        return Some(Outcome::AlreadySolved);
+    }
+
+    None
+}
+
+/// The response message is the only `<article>` on the page
+fn article(body: &str) -> Option<&str> {
+    let start = body.find("<article")?;
+    let end = body[start..].find("</article>")?;
+    Some(&body[start..start + end])
+}
+
+/// Parses the remaining time out of "you have 1m 5s left to wait"
+fn parse_wait(message: &str) -> Option<Duration> {
+    let end = message.find(" left to wait")?;
+    let start = message[..end].rfind("have ")? + "have ".len();
+
+    let mut seconds = 0;
+
+    for part in message[start..end].split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod test_submit {
+    use super::{parse_response, submit, Outcome, SubmitError};
+    use crate::answers::{Answers, Hint, Judgement};
+    use crate::client::test_server::serve;
+    use crate::client::Client;
+    use std::time::Duration;
+
+    const RESPONSES: [(&str, Outcome); 6] = [
+        (
+            "<main><article><p>That's the right answer!  You are one gold star closer to restoring snow operations. <a href=\"/2023/day/3#part2\">[Continue to Part Two]</a></p></article></main>",
+            Outcome::Correct,
+        ),
+        (
+            "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. <a href=\"/2023/day/3\">[Return to Day 3]</a></p></article></main>",
+            Outcome::Wrong(Some(Hint::TooHigh)),
+        ),
+        (
+            "<main><article><p>That's not the right answer; your answer is too low.  please wait one minute before trying again.</p></article></main>",
+            Outcome::Wrong(Some(Hint::TooLow)),
+        ),
+        (
+            "<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again.</p></article></main>",
+            Outcome::Wrong(None),
+        ),
+        (
+            "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2023/day/3\">[Return to Day 3]</a></p></article></main>",
+            Outcome::Wait(Duration::from_secs(65)),
+        ),
+        (
+            "<main><article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/3\">[Return to Day 3]</a></p></article></main>",
+            Outcome::AlreadySolved,
+        ),
+    ];
+
+    #[test]
+    fn test_parse_responses() {
+        for (body, expected) in RESPONSES.iter() {
+            assert_eq!(Some(expected), parse_response(body).as_ref(), "{body}");
+        }
+    }
+
+    #[test]
+    fn test_parse_unknown_response() {
+        assert_eq!(None, parse_response("<html>Something else</html>"));
+    }
+
+    #[test]
+    fn test_submit_records_outcome() {
+        let (base_url, server) = serve(vec![(200, RESPONSES[1].0), (200, RESPONSES[0].0)]);
+        let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+        let mut answers = Answers::default();
+
+        let outcome = submit(&mut client, &mut answers, "input.txt", (2023, 3, 1), "600");
+        assert_eq!(Outcome::Wrong(Some(Hint::TooHigh)), outcome.unwrap());
+
+        let outcome = submit(&mut client, &mut answers, "input.txt", (2023, 3, 1), "512");
+        assert_eq!(Outcome::Correct, outcome.unwrap());
+
+        assert_eq!(Some("512"), answers.get("input.txt", 1));
+        assert_eq!(2, server.join().unwrap().len());
+    }
+
+    #[test]
+    fn test_submit_refuses_known_wrong() {
+        // the server accepts no connections so any request would fail the test
+        let (base_url, server) = serve(vec![]);
+        let mut client = Client::new(&base_url, "secret", Duration::ZERO);
+
+        let mut answers = Answers::default();
+        answers.add_wrong("input.txt", 1, "600", Some(Hint::TooHigh));
+
+        let result = submit(&mut client, &mut answers, "input.txt", (2023, 3, 1), "600");
+        assert!(matches!(
+            result,
+            Err(SubmitError::Refused(Judgement::Wrong(Some(Hint::TooHigh))))
+        ));
+
+        let result = submit(&mut client, &mut answers, "input.txt", (2023, 3, 1), "700");
+        assert!(matches!(result, Err(SubmitError::Refused(_))));
+
+        assert!(server.join().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::answers::{self, Answers};
+use crate::input::{self, InputSource};
+use crate::registry::Puzzle;
+use std::fmt::{self, Display};
+
+/// Name of the input file whose answers are checked and submitted, matches the default input
+pub const INPUT_NAME: &str = "input.txt";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+    Error,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Unknown => "unknown",
+            Status::Error => "ERROR",
+        };
+
+        f.pad(name)
+    }
+}
+
+pub struct Check {
+    pub part: u8,
+    pub answer: Result<String, String>,
+    pub expected: Option<String>,
+}
+
+impl Check {
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.expected) {
+            (Err(_), _) => Status::Error,
+            (Ok(_), None) => Status::Unknown,
+            (Ok(answer), Some(expected)) if answer == expected => Status::Pass,
+            (Ok(_), Some(_)) => Status::Fail,
+        }
+    }
+}
+
+/// Solves both parts of a puzzle against its default input and compares them to the known answers
+pub fn verify(puzzle: &Puzzle) -> Vec<Check> {
+    let known = Answers::load(&answers::path(puzzle.year, puzzle.day));
+    let input = input::load(puzzle.year, puzzle.day, &InputSource::Default);
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let expected = known
+                .as_ref()
+                .ok()
+                .and_then(|known| known.get(INPUT_NAME, part))
+                .map(str::to_string);
+
+            let answer = match (&known, &input) {
+                (Err(err), _) => Err(err.to_string()),
+                (_, Err(err)) => Err(err.to_string()),
+                (Ok(_), Ok(input)) => puzzle.solve(input, part),
+            };
+
+            Check {
+                part,
+                answer,
+                expected,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_verify {
+    use super::{Check, Status};
+
+    fn check(answer: Result<&str, &str>, expected: Option<&str>) -> Check {
+        Check {
+            part: 1,
+            answer: answer.map(str::to_string).map_err(str::to_string),
+            expected: expected.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(Status::Pass, check(Ok("42"), Some("42")).status());
+        assert_eq!(Status::Fail, check(Ok("41"), Some("42")).status());
+        assert_eq!(Status::Unknown, check(Ok("42"), None).status());
+        assert_eq!(
+            Status::Error,
+            check(Err("missing input"), Some("42")).status()
+        );
+    }
+}
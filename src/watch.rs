@@ -0,0 +1,211 @@
+use crate::solution::Record;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// How long to wait for more changes after the first one, editors often write a file in several
+/// steps
+pub const DEFAULT_QUIET: Duration = Duration::from_millis(200);
+
+#[derive(Debug)]
+pub enum WatchError {
+    Notify(notify::Error),
+    Disconnected,
+}
+
+impl Display for WatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WatchError::Notify(err) => write!(f, "could not watch files: {err}"),
+            WatchError::Disconnected => write!(f, "file watcher stopped unexpectedly"),
+        }
+    }
+}
+
+impl From<notify::Error> for WatchError {
+    fn from(err: notify::Error) -> Self {
+        WatchError::Notify(err)
+    }
+}
+
+/// Watches files and directories for changes, using inotify on Linux
+pub struct Watcher {
+    // dropping the watcher stops it
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+}
+
+impl Watcher {
+    /// Starts watching `paths`, directories are watched recursively
+    pub fn new(paths: &[PathBuf]) -> Result<Self, WatchError> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+
+        for path in paths {
+            watcher.watch(path, RecursiveMode::Recursive)?;
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            events,
+        })
+    }
+
+    /// Blocks until a watched file changes and returns every path that changed until no more
+    /// changes arrived for `quiet`, temporary files that are already gone again are left out
+    pub fn wait(&self, quiet: Duration) -> Result<Vec<PathBuf>, WatchError> {
+        let mut changed = vec![];
+
+        while changed.is_empty() {
+            let event = self.events.recv().map_err(|_| WatchError::Disconnected)?;
+            collect(event?, &mut changed);
+        }
+
+        loop {
+            match self.events.recv_timeout(quiet) {
+                Ok(event) => collect(event?, &mut changed),
+                Err(RecvTimeoutError::Timeout) => {
+                    changed.retain(|path| path.exists());
+                    return Ok(changed);
+                }
+                Err(RecvTimeoutError::Disconnected) => return Err(WatchError::Disconnected),
+            }
+        }
+    }
+}
+
+fn collect(event: Event, changed: &mut Vec<PathBuf>) {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return;
+    }
+
+    for path in event.paths {
+        if !is_scratch_file(&path) && !changed.contains(&path) {
+            changed.push(path);
+        }
+    }
+}
+
+/// Swap and backup files editors write next to the file being edited
+fn is_scratch_file(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    name.starts_with('.') || name.ends_with('~') || name.ends_with(".swp")
+}
+
+/// How the answer of a part differs from the previous run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// The part did not run before
+    New,
+    Unchanged,
+    /// The part answered or failed with something else before
+    Changed(String),
+}
+
+/// Compares a part against the run of the same part in `previous`
+pub fn compare(previous: &[Record], record: &Record) -> Change {
+    let Some(before) = previous.iter().find(|before| {
+        (before.year, before.day, before.part) == (record.year, record.day, record.part)
+    }) else {
+        return Change::New;
+    };
+
+    if outcome(before) == outcome(record) {
+        Change::Unchanged
+    } else {
+        Change::Changed(outcome(before).to_string())
+    }
+}
+
+/// The answer of a part or the error it failed with
+pub fn outcome(record: &Record) -> &str {
+    match (&record.timed, &record.error) {
+        (Some(timed), _) => &timed.answer,
+        (None, Some(err)) => err,
+        (None, None) => "",
+    }
+}
+
+#[cfg(test)]
+mod test_watch {
+    use super::{compare, is_scratch_file, Change, Watcher};
+    use crate::solution::{Record, Timed};
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn record(part: u8, answer: Result<&str, &str>) -> Record {
+        Record {
+            year: 2023,
+            day: 3,
+            part,
+            input: "2023/03/input.txt".to_string(),
+            timed: answer.ok().map(|answer| Timed {
+                answer: answer.to_string(),
+                parse: Duration::from_micros(5),
+                solve: Duration::from_micros(7),
+            }),
+            error: answer.err().map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let previous = [record(1, Ok("4361")), record(2, Err("could not parse"))];
+
+        assert_eq!(
+            Change::Unchanged,
+            compare(&previous, &record(1, Ok("4361")))
+        );
+        assert_eq!(
+            Change::Changed("4361".to_string()),
+            compare(&previous, &record(1, Ok("4362")))
+        );
+        assert_eq!(
+            Change::Changed("could not parse".to_string()),
+            compare(&previous, &record(2, Ok("467835")))
+        );
+        assert_eq!(Change::New, compare(&previous[..1], &record(2, Ok("1"))));
+    }
+
+    #[test]
+    fn test_records_round_trip() {
+        let records = vec![record(1, Ok("4361")), record(2, Err("could not parse"))];
+
+        let json = serde_json::to_string(&records).unwrap();
+        let parsed: Vec<Record> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(records, parsed);
+    }
+
+    #[test]
+    fn test_scratch_files() {
+        assert!(is_scratch_file(Path::new("2023/03/.mod.rs.swp")));
+        assert!(is_scratch_file(Path::new("2023/03/mod.rs~")));
+        assert!(!is_scratch_file(Path::new("2023/03/mod.rs")));
+        assert!(!is_scratch_file(Path::new("2023/03/input.txt")));
+    }
+
+    #[test]
+    fn test_wait_for_change() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+
+        let watcher = Watcher::new(std::slice::from_ref(&dir)).unwrap();
+        std::fs::write(dir.join("mod.rs"), "// changed").unwrap();
+        std::fs::write(dir.join(".mod.rs.swp"), "").unwrap();
+        std::fs::write(dir.join("sed1234"), "").unwrap();
+        std::fs::rename(dir.join("sed1234"), dir.join("input.txt")).unwrap();
+
+        let changed = watcher.wait(Duration::from_millis(100)).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(vec![dir.join("mod.rs"), dir.join("input.txt")], changed);
+    }
+}